@@ -11,29 +11,29 @@ use multi_mono_font::{
     MultiMonoTextStyleBuilder, StaticText,
 };
 
-const UPPER_FONT: MultiMonoFont = MultiMonoFont {
-    image: ImageRaw::new(include_bytes!("fonts/upper.bin"), 96),
-    glyph_mapping: &StrGlyphMapping::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 26),
-    character_size: CharSize::new(6, 12),
-    character_spacing: 2,
-    baseline: 12,
-};
+const UPPER_FONT: MultiMonoFont = MultiMonoFont::new(
+    ImageRaw::new(include_bytes!("fonts/upper.bin"), 96),
+    &StrGlyphMapping::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 26),
+    CharSize::new(6, 12),
+    2,
+    12,
+);
 
-const LOWER_FONT: MultiMonoFont = MultiMonoFont {
-    image: ImageRaw::new(include_bytes!("fonts/lower.bin"), 128),
-    glyph_mapping: &StrGlyphMapping::new("abcdefghijklmnopqrstuvwxyz", 0),
-    character_size: CharSize::new(16, 32),
-    character_spacing: 5,
-    baseline: 32,
-};
+const LOWER_FONT: MultiMonoFont = MultiMonoFont::new(
+    ImageRaw::new(include_bytes!("fonts/lower.bin"), 128),
+    &StrGlyphMapping::new("abcdefghijklmnopqrstuvwxyz", 0),
+    CharSize::new(16, 32),
+    5,
+    32,
+);
 
-const HZ_FONT: MultiMonoFont = MultiMonoFont {
-    image: ImageRaw::new(include_bytes!("fonts/HZ.bin"), 96),
-    glyph_mapping: &StrGlyphMapping::new("字体测试", 0),
-    character_size: CharSize::new(24, 24),
-    character_spacing: 4,
-    baseline: 24,
-};
+const HZ_FONT: MultiMonoFont = MultiMonoFont::new(
+    ImageRaw::new(include_bytes!("fonts/HZ.bin"), 96),
+    &StrGlyphMapping::new("字体测试", 0),
+    CharSize::new(24, 24),
+    4,
+    24,
+);
 
 const MULTI_STYLE0: MultiMonoTextStyle<Rgb565> = MultiMonoTextStyleBuilder::new(Rgb565::RED)
     .font(
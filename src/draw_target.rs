@@ -1,25 +1,86 @@
+use core::{convert::Infallible, marker::PhantomData};
+
 use embedded_graphics::{
-    draw_target::DrawTarget, geometry::Dimensions, iterator::ContiguousIteratorExt,
-    pixelcolor::BinaryColor, primitives::Rectangle, Pixel,
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    image::GetPixel,
+    iterator::ContiguousIteratorExt,
+    pixelcolor::{BinaryColor, PixelColor, Rgb888, RgbColor},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
 };
 
-pub struct MultiMonoFontDrawTarget<'a, T, C> {
+use crate::multi_mono_text_style::envelope;
+
+/// Draw target which maps the binary glyph pixels to the colors of a text style.
+///
+/// `On` pixels are drawn in the text color, or in the foreground pattern if one is set. `Off`
+/// pixels are drawn in the background color, or skipped if the background color is `None`, which
+/// keeps the background transparent.
+pub struct MultiMonoFontDrawTarget<'a, 'p, T, C> {
     parent: &'a mut T,
     text_color: C,
     background_color: Option<C>,
+    fg_pattern: &'p [C],
 }
 
-impl<'a, T: DrawTarget, C> MultiMonoFontDrawTarget<'a, T, C> {
+impl<'a, 'p, T: DrawTarget, C> MultiMonoFontDrawTarget<'a, 'p, T, C> {
     pub fn new(parent: &'a mut T, text_color: C, background_color: Option<C>) -> Self {
         Self {
             parent,
             text_color,
             background_color,
+            fg_pattern: &[],
         }
     }
+
+    /// Sets the foreground pattern, which replaces the text color if it isn't empty.
+    pub fn with_fg_pattern(mut self, fg_pattern: &'p [C]) -> Self {
+        self.fg_pattern = fg_pattern;
+
+        self
+    }
+}
+
+/// Binary draw target for glyphs whose colors can change between glyphs.
+pub trait GlyphDrawTarget<'p, C>: DrawTarget<Color = BinaryColor> {
+    /// Sets the colors of the following glyphs.
+    fn set_colors(&mut self, text_color: C, background_color: Option<C>, fg_pattern: &'p [C]);
+
+    /// Returns `true` if `Off` pixels are drawn.
+    fn fills_background(&self) -> bool;
+}
+
+impl<'p, T: DrawTarget> GlyphDrawTarget<'p, T::Color>
+    for MultiMonoFontDrawTarget<'_, 'p, T, T::Color>
+{
+    fn set_colors(
+        &mut self,
+        text_color: T::Color,
+        background_color: Option<T::Color>,
+        fg_pattern: &'p [T::Color],
+    ) {
+        self.text_color = text_color;
+        self.background_color = background_color;
+        self.fg_pattern = fg_pattern;
+    }
+
+    fn fills_background(&self) -> bool {
+        self.background_color.is_some()
+    }
 }
 
-impl<T: DrawTarget> DrawTarget for MultiMonoFontDrawTarget<'_, T, T::Color> {
+/// Returns the foreground color at the given position.
+///
+/// The rows of the pattern are repeated vertically, the text color is used if the pattern is empty.
+fn foreground_color<C: Copy>(text_color: C, fg_pattern: &[C], position: Point) -> C {
+    match fg_pattern.len() {
+        0 => text_color,
+        len => fg_pattern[position.y.rem_euclid(len as i32) as usize],
+    }
+}
+
+impl<T: DrawTarget> DrawTarget for MultiMonoFontDrawTarget<'_, '_, T, T::Color> {
     type Color = BinaryColor;
     type Error = T::Error;
 
@@ -27,19 +88,23 @@ impl<T: DrawTarget> DrawTarget for MultiMonoFontDrawTarget<'_, T, T::Color> {
     where
         I: IntoIterator<Item = Self::Color>,
     {
+        let text_color = self.text_color;
+        let background_color = self.background_color;
+        let fg_pattern = self.fg_pattern;
+
         self.parent.draw_iter(
             colors
                 .into_iter()
                 .into_pixels(area)
-                .filter(|Pixel(_, color)| color.is_on() || self.background_color.is_some())
+                .filter(|Pixel(_, color)| color.is_on() || background_color.is_some())
                 .map(|Pixel(pos, pixel_color)| {
                     let color = if pixel_color.is_on() {
-                        self.text_color
+                        foreground_color(text_color, fg_pattern, pos)
                     } else {
-                        if let Some(background_color) = self.background_color {
+                        if let Some(background_color) = background_color {
                             background_color
                         } else {
-                            self.text_color
+                            text_color
                         }
                     };
                     Pixel(pos, color)
@@ -56,6 +121,15 @@ impl<T: DrawTarget> DrawTarget for MultiMonoFontDrawTarget<'_, T, T::Color> {
 
     fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
         match color {
+            BinaryColor::On if !self.fg_pattern.is_empty() => {
+                let text_color = self.text_color;
+                let fg_pattern = self.fg_pattern;
+
+                self.parent.draw_iter(
+                    area.points()
+                        .map(|pos| Pixel(pos, foreground_color(text_color, fg_pattern, pos))),
+                )
+            }
             BinaryColor::On => self.parent.fill_solid(area, self.text_color),
             BinaryColor::Off => {
                 if let Some(background_color) = self.background_color {
@@ -72,7 +146,579 @@ impl<T: DrawTarget> DrawTarget for MultiMonoFontDrawTarget<'_, T, T::Color> {
     }
 }
 
-impl<T: DrawTarget, C> Dimensions for MultiMonoFontDrawTarget<'_, T, C> {
+impl<T: DrawTarget, C> Dimensions for MultiMonoFontDrawTarget<'_, '_, T, C> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Draw target which fades pixels near the left and right edges toward the background color.
+///
+/// The fade zones are measured from the edges of the bounding box of the parent draw target.
+pub struct EdgeFadeDrawTarget<'a, T: DrawTarget> {
+    parent: &'a mut T,
+    area: Rectangle,
+    fade: (u32, u32),
+    background_color: T::Color,
+}
+
+impl<'a, T: DrawTarget> EdgeFadeDrawTarget<'a, T> {
+    pub fn new(parent: &'a mut T, fade: (u32, u32), background_color: T::Color) -> Self {
+        Self {
+            area: parent.bounding_box(),
+            parent,
+            fade,
+            background_color,
+        }
+    }
+}
+
+/// Returns the weight of the original color at the given x coordinate, in 1/256.
+fn fade_weight(area: &Rectangle, (left, right): (u32, u32), x: i32) -> u32 {
+    let from_left = (x - area.top_left.x).max(0) as u32;
+    let from_right = (area.top_left.x + area.size.width as i32 - 1 - x).max(0) as u32;
+
+    let left_weight = if from_left < left {
+        from_left * 256 / left
+    } else {
+        256
+    };
+    let right_weight = if from_right < right {
+        from_right * 256 / right
+    } else {
+        256
+    };
+
+    left_weight.min(right_weight)
+}
+
+/// Blends two colors, `weight` is the weight of `color` in 1/256.
+fn blend(color: Rgb888, background: Rgb888, weight: u32) -> Rgb888 {
+    let channel = |c: u8, b: u8| ((c as u32 * weight + b as u32 * (256 - weight)) / 256) as u8;
+
+    Rgb888::new(
+        channel(color.r(), background.r()),
+        channel(color.g(), background.g()),
+        channel(color.b(), background.b()),
+    )
+}
+
+impl<T> DrawTarget for EdgeFadeDrawTarget<'_, T>
+where
+    T: DrawTarget,
+    T::Color: Into<Rgb888> + From<Rgb888>,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let area = self.area;
+        let fade = self.fade;
+        let background = self.background_color.into();
+
+        self.parent
+            .draw_iter(pixels.into_iter().map(|Pixel(pos, color)| {
+                match fade_weight(&area, fade, pos.x) {
+                    256 => Pixel(pos, color),
+                    weight => Pixel(pos, blend(color.into(), background, weight).into()),
+                }
+            }))
+    }
+}
+
+impl<T: DrawTarget> Dimensions for EdgeFadeDrawTarget<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Draw target which blends the drawn pixels with the pixels of the parent.
+pub struct OpacityDrawTarget<'a, T> {
+    parent: &'a mut T,
+    weight: u32,
+}
+
+impl<'a, T> OpacityDrawTarget<'a, T> {
+    pub fn new(parent: &'a mut T, opacity: u8) -> Self {
+        Self {
+            parent,
+            weight: opacity as u32 * 256 / 255,
+        }
+    }
+}
+
+impl<T> DrawTarget for OpacityDrawTarget<'_, T>
+where
+    T: DrawTarget + GetPixel<Color = <T as DrawTarget>::Color>,
+    <T as DrawTarget>::Color: Into<Rgb888> + From<Rgb888>,
+{
+    type Color = <T as DrawTarget>::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(pos, color) in pixels {
+            // pixels outside the parent are clipped by the parent
+            let color = match self.parent.pixel(pos) {
+                Some(background) => blend(color.into(), background.into(), self.weight).into(),
+                None => color,
+            };
+
+            self.parent.draw_iter(core::iter::once(Pixel(pos, color)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: DrawTarget> Dimensions for OpacityDrawTarget<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Color channel of an RGB color.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+/// Draw target which only draws a single color channel of the drawn pixels.
+///
+/// The pixels are moved horizontally by `offset` and the other channels of the parent pixels are
+/// kept, which makes it possible to compose sub-pixel rendered text from one pass per channel.
+pub struct ChannelDrawTarget<'a, T> {
+    parent: &'a mut T,
+    channel: Channel,
+    offset: i32,
+}
+
+impl<'a, T> ChannelDrawTarget<'a, T> {
+    pub fn new(parent: &'a mut T, channel: Channel, offset: i32) -> Self {
+        Self {
+            parent,
+            channel,
+            offset,
+        }
+    }
+}
+
+impl<T> DrawTarget for ChannelDrawTarget<'_, T>
+where
+    T: DrawTarget + GetPixel<Color = <T as DrawTarget>::Color>,
+    <T as DrawTarget>::Color: Into<Rgb888> + From<Rgb888>,
+{
+    type Color = <T as DrawTarget>::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(pos, color) in pixels {
+            let pos = pos + Point::new(self.offset, 0);
+            // pixels outside the parent are skipped
+            let Some(background) = self.parent.pixel(pos) else {
+                continue;
+            };
+
+            let (color, background): (Rgb888, Rgb888) = (color.into(), background.into());
+            let color = match self.channel {
+                Channel::Red => Rgb888::new(color.r(), background.g(), background.b()),
+                Channel::Green => Rgb888::new(background.r(), color.g(), background.b()),
+                Channel::Blue => Rgb888::new(background.r(), background.g(), color.b()),
+            };
+
+            self.parent
+                .draw_iter(core::iter::once(Pixel(pos, color.into())))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: DrawTarget> Dimensions for ChannelDrawTarget<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Draw target which XORs the drawn pixels with the pixels of the parent.
+///
+/// Drawing the same pixels twice restores the original content of the parent.
+pub struct XorDrawTarget<'a, T> {
+    parent: &'a mut T,
+}
+
+impl<'a, T> XorDrawTarget<'a, T> {
+    pub fn new(parent: &'a mut T) -> Self {
+        Self { parent }
+    }
+}
+
+impl<T> DrawTarget for XorDrawTarget<'_, T>
+where
+    T: DrawTarget<Color = BinaryColor> + GetPixel<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(pos, color) in pixels {
+            // pixels outside the parent are clipped by the parent
+            let color = match self.parent.pixel(pos) {
+                Some(BinaryColor::On) => color.invert(),
+                _ => color,
+            };
+
+            self.parent.draw_iter(core::iter::once(Pixel(pos, color)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: DrawTarget> Dimensions for XorDrawTarget<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Draw target which downscales the drawn pixels by an integer factor.
+///
+/// Only pixels whose offset from the origin is a multiple of the factor in both directions are
+/// drawn, at the origin plus the offset divided by the factor.
+pub struct ShrinkDrawTarget<'a, T> {
+    parent: &'a mut T,
+    origin: Point,
+    factor: i32,
+}
+
+impl<'a, T: DrawTarget> ShrinkDrawTarget<'a, T> {
+    pub fn new(parent: &'a mut T, origin: Point, factor: u8) -> Self {
+        Self {
+            parent,
+            origin,
+            factor: factor as i32,
+        }
+    }
+}
+
+impl<T: DrawTarget> DrawTarget for ShrinkDrawTarget<'_, T> {
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let origin = self.origin;
+        let factor = self.factor;
+
+        self.parent
+            .draw_iter(pixels.into_iter().filter_map(|Pixel(pos, color)| {
+                let offset = pos - origin;
+                if offset.x.rem_euclid(factor) == 0 && offset.y.rem_euclid(factor) == 0 {
+                    Some(Pixel(origin + offset / factor, color))
+                } else {
+                    None
+                }
+            }))
+    }
+}
+
+impl<'p, T, C> GlyphDrawTarget<'p, C> for ShrinkDrawTarget<'_, T>
+where
+    T: GlyphDrawTarget<'p, C>,
+{
+    fn set_colors(&mut self, text_color: C, background_color: Option<C>, fg_pattern: &'p [C]) {
+        self.parent
+            .set_colors(text_color, background_color, fg_pattern);
+    }
+
+    fn fills_background(&self) -> bool {
+        self.parent.fills_background()
+    }
+}
+
+impl<T: DrawTarget> Dimensions for ShrinkDrawTarget<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+/// Draw target which reports the coverage of the glyph pixels to a callback.
+///
+/// `On` pixels have a coverage of `255` and `Off` pixels of the glyph images a coverage of `0`.
+/// The background outside of the glyph images isn't reported.
+pub struct CoverageDrawTarget<F> {
+    callback: F,
+}
+
+impl<F: FnMut(Point, u8)> CoverageDrawTarget<F> {
+    pub fn new(callback: F) -> Self {
+        Self { callback }
+    }
+}
+
+impl<F: FnMut(Point, u8)> DrawTarget for CoverageDrawTarget<F> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            let coverage = match color {
+                BinaryColor::On => 255,
+                BinaryColor::Off => 0,
+            };
+            (self.callback)(point, coverage);
+        }
+
+        Ok(())
+    }
+}
+
+/// The coverage doesn't depend on the colors of the glyphs.
+impl<F: FnMut(Point, u8), C> GlyphDrawTarget<'_, C> for CoverageDrawTarget<F> {
+    fn set_colors(&mut self, _: C, _: Option<C>, _: &[C]) {}
+
+    fn fills_background(&self) -> bool {
+        false
+    }
+}
+
+/// The coverage is reported for all pixels, the bounding box isn't used for clipping.
+impl<F> Dimensions for CoverageDrawTarget<F> {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::zero()
+    }
+}
+
+/// Draw target which records the bounding box of all drawn pixels.
+pub struct BoundsDrawTarget<C> {
+    bounds: Rectangle,
+    color: PhantomData<C>,
+}
+
+impl<C> BoundsDrawTarget<C> {
+    pub fn new() -> Self {
+        Self {
+            bounds: Rectangle::zero(),
+            color: PhantomData,
+        }
+    }
+
+    /// Returns the bounding box of the drawn pixels.
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+}
+
+impl<C: PixelColor> DrawTarget for BoundsDrawTarget<C> {
+    type Color = C;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, _) in pixels {
+            self.bounds = envelope(&self.bounds, &Rectangle::new(point, Size::new(1, 1)));
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, _: Self::Color) -> Result<(), Self::Error> {
+        self.bounds = envelope(&self.bounds, area);
+
+        Ok(())
+    }
+}
+
+/// All pixels are recorded, the bounding box isn't used for clipping.
+impl<C> Dimensions for BoundsDrawTarget<C> {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::zero()
+    }
+}
+
+/// Draw target which computes a fingerprint of all drawn pixels.
+///
+/// The fingerprint is a hash of the pixel positions and of whether each pixel has the color of the
+/// first drawn pixel. Equal strings are drawn in the same order, so they have equal fingerprints.
+pub struct FingerprintDrawTarget<C> {
+    bounding_box: Rectangle,
+    first_color: Option<C>,
+    hash: u32,
+}
+
+impl<C> FingerprintDrawTarget<C> {
+    /// Creates a draw target with the given bounding box, which should contain the drawn pixels.
+    pub fn new(bounding_box: Rectangle) -> Self {
+        Self {
+            bounding_box,
+            first_color: None,
+            hash: FNV_OFFSET_BASIS,
+        }
+    }
+
+    /// Returns the fingerprint of the drawn pixels.
+    pub fn fingerprint(&self) -> u32 {
+        self.hash
+    }
+}
+
+const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+impl<C: PixelColor> DrawTarget for FingerprintDrawTarget<C> {
+    type Color = C;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            let first_color = *self.first_color.get_or_insert(color);
+            for value in [
+                point.x as u32,
+                point.y as u32,
+                u32::from(color == first_color),
+            ] {
+                for byte in value.to_le_bytes() {
+                    self.hash = (self.hash ^ u32::from(byte)).wrapping_mul(FNV_PRIME);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// All pixels are recorded, also outside the bounding box.
+impl<C> Dimensions for FingerprintDrawTarget<C> {
+    fn bounding_box(&self) -> Rectangle {
+        self.bounding_box
+    }
+}
+
+/// Returns the area which is drawn by a [`ShrinkDrawTarget`] for the given area.
+pub fn shrink_rect(area: &Rectangle, origin: Point, factor: u8) -> Rectangle {
+    let factor = factor as i32;
+    let ceil_div = |value: i32| -(-value).div_euclid(factor);
+
+    let start = area.top_left - origin;
+    let end = start + area.size;
+    let start = Point::new(ceil_div(start.x), ceil_div(start.y));
+    let end = Point::new(ceil_div(end.x), ceil_div(end.y));
+
+    Rectangle::new(
+        origin + start,
+        Size::new((end.x - start.x) as u32, (end.y - start.y) as u32),
+    )
+}
+
+/// Mapping from font pixel colors to draw target colors.
+///
+/// A color map can be passed to [`MultiMonoTextStyle::draw_string_mapped`] to choose the colors
+/// for glyph and background pixels independently of the colors of the style.
+///
+/// [`MultiMonoTextStyle::draw_string_mapped`]: crate::MultiMonoTextStyle::draw_string_mapped
+pub trait ColorMap<C> {
+    /// Returns the draw target color for a font pixel, or `None` if the pixel isn't drawn.
+    fn map(&self, color: BinaryColor) -> Option<C>;
+}
+
+/// Color map with fixed colors for glyph and background pixels.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct BinaryColorMap<C> {
+    /// Color of glyph pixels, `None` for transparent pixels.
+    pub on: Option<C>,
+
+    /// Color of background pixels, `None` for transparent pixels.
+    pub off: Option<C>,
+}
+
+impl<C: Copy> ColorMap<C> for BinaryColorMap<C> {
+    fn map(&self, color: BinaryColor) -> Option<C> {
+        match color {
+            BinaryColor::On => self.on,
+            BinaryColor::Off => self.off,
+        }
+    }
+}
+
+/// Draw target which maps font pixels to the parent colors using a [`ColorMap`].
+pub struct ColorMapDrawTarget<'a, 'm, T, M> {
+    parent: &'a mut T,
+    color_map: &'m M,
+}
+
+impl<'a, 'm, T, M> ColorMapDrawTarget<'a, 'm, T, M> {
+    pub fn new(parent: &'a mut T, color_map: &'m M) -> Self {
+        Self { parent, color_map }
+    }
+}
+
+impl<T, M> DrawTarget for ColorMapDrawTarget<'_, '_, T, M>
+where
+    T: DrawTarget,
+    M: ColorMap<T::Color>,
+{
+    type Color = BinaryColor;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let color_map = self.color_map;
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .filter_map(|Pixel(pos, color)| color_map.map(color).map(|c| Pixel(pos, c))),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        match self.color_map.map(color) {
+            Some(color) => self.parent.fill_solid(area, color),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The colors are chosen by the color map, the colors of the glyphs are ignored.
+impl<T, M> GlyphDrawTarget<'_, T::Color> for ColorMapDrawTarget<'_, '_, T, M>
+where
+    T: DrawTarget,
+    M: ColorMap<T::Color>,
+{
+    fn set_colors(&mut self, _: T::Color, _: Option<T::Color>, _: &[T::Color]) {}
+
+    fn fills_background(&self) -> bool {
+        self.color_map.map(BinaryColor::Off).is_some()
+    }
+}
+
+impl<T: DrawTarget, M> Dimensions for ColorMapDrawTarget<'_, '_, T, M> {
     fn bounding_box(&self) -> Rectangle {
         self.parent.bounding_box()
     }
@@ -28,6 +28,9 @@ pub const FONT_4X6: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(4, 6),
     character_spacing: 0,
     baseline: 4,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 5x7 pixel monospace font.
@@ -40,6 +43,9 @@ pub const FONT_5X7: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(5, 7),
     character_spacing: 0,
     baseline: 5,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 5x8 pixel monospace font.
@@ -52,6 +58,9 @@ pub const FONT_5X8: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(5, 8),
     character_spacing: 0,
     baseline: 6,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 6x9 pixel monospace font.
@@ -64,6 +73,9 @@ pub const FONT_6X9: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(6, 9),
     character_spacing: 0,
     baseline: 6,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 6x10 pixel monospace font.
@@ -76,6 +88,9 @@ pub const FONT_6X10: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(6, 10),
     character_spacing: 0,
     baseline: 7,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 6x12 pixel monospace font.
@@ -88,6 +103,9 @@ pub const FONT_6X12: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(6, 12),
     character_spacing: 0,
     baseline: 9,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 6x13 pixel monospace font.
@@ -100,6 +118,9 @@ pub const FONT_6X13: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(6, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 6x13 pixel monospace font.
@@ -112,6 +133,9 @@ pub const FONT_6X13_BOLD: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(6, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 6x13 pixel monospace font.
@@ -124,6 +148,9 @@ pub const FONT_6X13_ITALIC: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(6, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 7x13 pixel monospace font.
@@ -136,6 +163,9 @@ pub const FONT_7X13: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(7, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 7x13 pixel monospace font.
@@ -148,6 +178,9 @@ pub const FONT_7X13_BOLD: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(7, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 7x13 pixel monospace font.
@@ -160,6 +193,9 @@ pub const FONT_7X13_ITALIC: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(7, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 7x14 pixel monospace font.
@@ -172,6 +208,9 @@ pub const FONT_7X14: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(7, 14),
     character_spacing: 0,
     baseline: 11,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 7x14 pixel monospace font.
@@ -184,6 +223,9 @@ pub const FONT_7X14_BOLD: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(7, 14),
     character_spacing: 0,
     baseline: 11,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 8x13 pixel monospace font.
@@ -196,6 +238,9 @@ pub const FONT_8X13: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(8, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 8x13 pixel monospace font.
@@ -208,6 +253,9 @@ pub const FONT_8X13_BOLD: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(8, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 8x13 pixel monospace font.
@@ -220,6 +268,9 @@ pub const FONT_8X13_ITALIC: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(8, 13),
     character_spacing: 0,
     baseline: 10,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 9x15 pixel monospace font.
@@ -232,6 +283,9 @@ pub const FONT_9X15: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(9, 15),
     character_spacing: 0,
     baseline: 11,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 9x15 pixel monospace font.
@@ -244,6 +298,9 @@ pub const FONT_9X15_BOLD: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(9, 15),
     character_spacing: 0,
     baseline: 11,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 9x18 pixel monospace font.
@@ -256,6 +313,9 @@ pub const FONT_9X18: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(9, 18),
     character_spacing: 0,
     baseline: 13,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 9x18 pixel monospace font.
@@ -268,6 +328,9 @@ pub const FONT_9X18_BOLD: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(9, 18),
     character_spacing: 0,
     baseline: 13,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
 
 /// 10x20 pixel monospace font.
@@ -280,4 +343,7 @@ pub const FONT_10X20: crate::MultiMonoFont = crate::MultiMonoFont {
     character_size: CharSize::new(10, 20),
     character_spacing: 0,
     baseline: 15,
+    baselines: None,
+    membership_cache: None,
+    offset_mapping: None,
 };
@@ -0,0 +1,42 @@
+use embedded_graphics::primitives::Rectangle;
+
+use crate::MultiMonoFont;
+
+/// Number of entries in the glyph cache.
+const GLYPH_CACHE_SIZE: usize = 8;
+
+/// Cache for the glyph areas of recently drawn characters.
+///
+/// Entries are keyed by the character and the address of the font, because the same character can
+/// be drawn with different fonts. The oldest entry is replaced if the cache is full.
+pub(crate) struct GlyphCache<'a> {
+    entries: [Option<(char, &'a MultiMonoFont<'a>, Rectangle)>; GLYPH_CACHE_SIZE],
+    next: usize,
+}
+
+impl<'a> GlyphCache<'a> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: [None; GLYPH_CACHE_SIZE],
+            next: 0,
+        }
+    }
+
+    /// Returns the area of the glyph in the font image.
+    pub(crate) fn glyph_rect(&mut self, c: char, font: &'a MultiMonoFont<'a>) -> Rectangle {
+        let cached = self
+            .entries
+            .iter()
+            .flatten()
+            .find(|(cached_c, cached_font, _)| *cached_c == c && core::ptr::eq(*cached_font, font));
+        if let Some((_, _, area)) = cached {
+            return *area;
+        }
+
+        let area = font.glyph_atlas_rect(c);
+        self.entries[self.next] = Some((c, font, area));
+        self.next = (self.next + 1) % GLYPH_CACHE_SIZE;
+
+        area
+    }
+}
@@ -1,22 +1,35 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod char_size;
 mod draw_target;
 mod generated;
+mod glyph_cache;
 pub mod mapping;
 mod multi_mono_text_style;
+mod paragraph;
 mod static_text;
 mod sub_image;
+mod text_cursor;
+mod vertical_text;
+mod wrap;
 
 use core::fmt;
 
 pub use char_size::CharSize;
+pub use draw_target::{BinaryColorMap, ColorMap};
 pub use generated::*;
-use mapping::StrGlyphMapping;
+use mapping::{GlyphMapping, OffsetGlyphMapping, StrGlyphMapping};
 pub use multi_mono_text_style::{
-    MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    BlendMode, DrawCursor, FontTieBreak, GlyphMetrics, MultiMonoLineHeight, MultiMonoTextStyle,
+    MultiMonoTextStyleBuilder, VerticalGlyphAlign,
 };
-pub use static_text::StaticText;
+pub use paragraph::Paragraph;
+pub use static_text::{Overflow, StaticText};
+pub use text_cursor::TextCursor;
+pub use vertical_text::{TextOrientation, VerticalText};
 
 use embedded_graphics::{
     geometry::{OriginDimensions, Point},
@@ -33,9 +46,11 @@ pub type ChSzTy = u16;
 
 /// Monospaced bitmap font.
 ///
-/// See the [module documentation] for more information about using fonts.
+/// See the [module documentation] for more information about using fonts. Use [`new`] to define
+/// custom fonts, struct literals need to be updated when optional fields are added.
 ///
 /// [module documentation]: self
+/// [`new`]: MultiMonoFont::new
 #[derive(Clone, Copy)]
 pub struct MultiMonoFont<'a> {
     /// Raw image data containing the font.
@@ -55,38 +70,441 @@ pub struct MultiMonoFont<'a> {
     /// Offset from the top of the glyph bounding box to the baseline.
     pub baseline: ChSzTy,
 
+    /// Per-glyph baselines.
+    ///
+    /// Optional table, indexed by glyph index, which overrides [`baseline`] for individual glyphs.
+    /// Glyphs without an entry in the table use [`baseline`]. The table can be set with
+    /// [`with_baselines`].
+    ///
+    /// [`baseline`]: MultiMonoFont::baseline
+    /// [`with_baselines`]: MultiMonoFont::with_baselines
+    pub baselines: Option<&'a [ChSzTy]>,
+
+    /// Sorted list of the disjoint inclusive character ranges in the glyph mapping.
+    ///
+    /// If set, [`contains`] uses a binary search in this list instead of scanning the glyph
+    /// mapping. The list can be built with `with_membership_cache` if the `alloc` feature is
+    /// enabled.
+    ///
+    /// [`contains`]: MultiMonoFont::contains
+    pub membership_cache: Option<&'a [(char, char)]>,
+
+    /// Offset table glyph mapping.
+    ///
+    /// If set, glyph lookups use this mapping instead of [`glyph_mapping`]. The mapping can be set
+    /// with [`with_offset_mapping`].
+    ///
+    /// [`glyph_mapping`]: MultiMonoFont::glyph_mapping
+    /// [`with_offset_mapping`]: MultiMonoFont::with_offset_mapping
+    pub offset_mapping: Option<&'a OffsetGlyphMapping<'a>>,
+
     /// Glyph mapping.
     pub glyph_mapping: &'a StrGlyphMapping<'a>,
 }
 
-impl MultiMonoFont<'_> {
-    /// Returns a subimage for a glyph.
-    pub(crate) fn glyph(&self, c: char) -> SubImage<'_, ImageRaw<BinaryColor>> {
+impl<'a> MultiMonoFont<'a> {
+    /// Creates a new font.
+    ///
+    /// The font has no per-glyph baselines, membership cache or offset mapping, these can be
+    /// added with the `with_*` methods. Unlike a struct literal, code which uses this constructor
+    /// doesn't need to be changed if optional fields are added to the font.
+    ///
+    /// ```
+    /// use multi_mono_font::{ascii::FONT_6X9, mapping::ASCII, CharSize, MultiMonoFont};
+    ///
+    /// const FONT: MultiMonoFont = MultiMonoFont::new(FONT_6X9.image, &ASCII, CharSize::new(6, 9), 0, 6);
+    /// assert!(FONT.structural_eq(&FONT_6X9));
+    /// ```
+    pub const fn new(
+        image: ImageRaw<'a, BinaryColor>,
+        glyph_mapping: &'a StrGlyphMapping<'a>,
+        character_size: CharSize,
+        character_spacing: ChSzTy,
+        baseline: ChSzTy,
+    ) -> Self {
+        Self {
+            image,
+            character_size,
+            character_spacing,
+            baseline,
+            baselines: None,
+            membership_cache: None,
+            offset_mapping: None,
+            glyph_mapping,
+        }
+    }
+
+    /// Returns a copy of this font which uses a per-glyph baseline table.
+    ///
+    /// See [`baselines`] for the contents of the table.
+    ///
+    /// [`baselines`]: MultiMonoFont::baselines
+    pub const fn with_baselines(self, baselines: &'a [ChSzTy]) -> Self {
+        Self {
+            baselines: Some(baselines),
+            ..self
+        }
+    }
+
+    /// Returns if the font contains a glyph for the given character.
+    pub fn contains(&self, c: char) -> bool {
+        match self.membership_cache {
+            Some(cache) => {
+                let index = cache.partition_point(|&(_, end)| end < c);
+                cache.get(index).is_some_and(|&(start, _)| start <= c)
+            }
+            None => self.mapping().contains(c),
+        }
+    }
+
+    /// Returns the glyph mapping which is used for glyph lookups.
+    fn mapping(&self) -> &dyn GlyphMapping {
+        match self.offset_mapping {
+            Some(offset_mapping) => offset_mapping,
+            None => self.glyph_mapping,
+        }
+    }
+
+    /// Returns a copy of this font which uses an offset table glyph mapping.
+    ///
+    /// The offset mapping must map the characters to the same glyph indices as the glyph
+    /// mapping, but avoids scanning the mapping string for every glyph lookup.
+    ///
+    /// ```
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9,
+    ///     mapping::{OffsetGlyphMapping, ASCII},
+    ///     MultiMonoFont,
+    /// };
+    ///
+    /// const OFFSET_MAPPING: OffsetGlyphMapping =
+    ///     OffsetGlyphMapping::new(&[0x20, 0x7f, 0], ASCII.replacement_index());
+    /// const FONT: MultiMonoFont = FONT_6X9.with_offset_mapping(&OFFSET_MAPPING);
+    ///
+    /// assert_eq!(FONT.rows_needed(), FONT_6X9.rows_needed());
+    /// for c in ('\0'..='\u{ff}').chain(['\u{4e00}']) {
+    ///     assert_eq!(FONT.contains(c), FONT_6X9.contains(c));
+    ///     assert_eq!(FONT.glyph_baseline(c), FONT_6X9.glyph_baseline(c));
+    /// }
+    /// ```
+    pub const fn with_offset_mapping(self, offset_mapping: &'a OffsetGlyphMapping<'a>) -> Self {
+        Self {
+            offset_mapping: Some(offset_mapping),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this font which uses a membership cache for [`contains`].
+    ///
+    /// The sorted character ranges of the glyph mapping are built into `cache`, which speeds up
+    /// the font selection of multi-font styles for fonts with large or sparse mappings. Adjacent
+    /// and overlapping ranges are merged, so the cache size depends on the number of ranges and
+    /// not on the number of characters.
+    ///
+    /// ```
+    /// use multi_mono_font::{ascii::FONT_6X9, mapping::StrGlyphMapping, MultiMonoFont};
+    ///
+    /// let font = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("z\0AFbG\0\u{4e00}\u{9fff}", 0),
+    ///     ..FONT_6X9
+    /// };
+    ///
+    /// let mut cache = Vec::new();
+    /// let cached = font.with_membership_cache(&mut cache);
+    ///
+    /// assert_eq!(cached.membership_cache.unwrap(), [('A', 'G'), ('b', 'b'), ('z', 'z'), ('\u{4e00}', '\u{9fff}')]);
+    /// for c in ('\0'..='\u{a000}').chain(['😀']) {
+    ///     assert_eq!(cached.contains(c), font.contains(c));
+    /// }
+    /// ```
+    ///
+    /// [`contains`]: MultiMonoFont::contains
+    #[cfg(feature = "alloc")]
+    pub fn with_membership_cache(self, cache: &'a mut alloc::vec::Vec<(char, char)>) -> Self {
+        cache.clear();
+        match self.offset_mapping {
+            Some(offset_mapping) => {
+                cache.extend(offset_mapping.table().chunks_exact(3).map(|block| {
+                    // surrogates can't be looked up, clamp the range to valid characters
+                    let start = char::from_u32(block[0].into()).unwrap_or('\u{e000}');
+                    let end = char::from_u32(block[1].into()).unwrap_or('\u{d7ff}');
+
+                    (start, end)
+                }))
+            }
+            None => cache.extend(
+                self.glyph_mapping
+                    .ranges()
+                    .map(|(_, range)| (*range.start(), *range.end())),
+            ),
+        }
+        cache.retain(|(start, end)| start <= end);
+        cache.sort_unstable();
+        cache.dedup_by(|(start, end), (_, previous_end)| {
+            let merge = *start as u32 <= *previous_end as u32 + 1;
+            if merge {
+                *previous_end = (*previous_end).max(*end);
+            }
+
+            merge
+        });
+
+        Self {
+            membership_cache: Some(cache),
+            ..self
+        }
+    }
+
+    /// Returns the width of a single line of text drawn with this font.
+    ///
+    /// The width is calculated at compile time if the function is used in a const context, which
+    /// makes it possible to derive layout constants from fixed labels. Every character is counted
+    /// with the raw advance of this font: newlines, tabs, combining characters and the options of
+    /// a [`MultiMonoTextStyle`] aren't taken into account. The result only matches the width
+    /// measured by a style which uses this font and the default settings for single lines without
+    /// these characters.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// const OK_WIDTH: u32 = FONT_6X9.text_width("OK");
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let metrics = style.measure_string("OK", Point::zero(), Baseline::Top);
+    /// assert_eq!(OK_WIDTH, metrics.bounding_box.size.width);
+    /// assert_eq!(FONT_6X9.text_width(""), 0);
+    /// ```
+    pub const fn text_width(&self, text: &str) -> u32 {
+        let bytes = text.as_bytes();
+        let mut index = 0;
+        let mut char_count = 0;
+
+        while index < bytes.len() {
+            // count the first byte of each UTF-8 encoded character
+            if bytes[index] & 0xC0 != 0x80 {
+                char_count += 1;
+            }
+            index += 1;
+        }
+
+        match char_count {
+            0 => 0,
+            _ => {
+                let advance = self.character_size.width as u32 + self.character_spacing as u32;
+
+                char_count * advance - self.character_spacing as u32
+            }
+        }
+    }
+
+    /// Returns the number of glyphs in each row of the font image.
+    ///
+    /// This isn't a `const fn`, because the size of an [`ImageRaw`] is only available through the
+    /// [`OriginDimensions`] trait. Use [`const_assert_font`] to check the image size at compile
+    /// time.
+    ///
+    /// [`OriginDimensions`]: embedded_graphics::geometry::OriginDimensions
+    pub fn glyphs_per_row(&self) -> u32 {
+        match self.character_size.width {
+            0 => 0,
+            width => self.image.size().width / width as u32,
+        }
+    }
+
+    /// Returns the number of glyph rows which are needed for all glyphs in the glyph mapping.
+    ///
+    /// This can be used to verify that the font image is large enough. Like
+    /// [`glyphs_per_row`], it can't be evaluated in a const context, use [`const_assert_font`] to
+    /// check this at compile time.
+    ///
+    /// ```
+    /// use embedded_graphics::{image::ImageRaw, pixelcolor::BinaryColor};
+    /// use multi_mono_font::{mapping::StrGlyphMapping, CharSize, MultiMonoFont};
+    ///
+    /// const FONT: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::<BinaryColor>::new(&[0; 288], 96),
+    ///     &StrGlyphMapping::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 26),
+    ///     CharSize::new(6, 12),
+    ///     0,
+    ///     12,
+    /// );
+    ///
+    /// assert_eq!(FONT.glyphs_per_row(), 16);
+    /// assert_eq!(FONT.rows_needed(), 2);
+    /// ```
+    ///
+    /// [`glyphs_per_row`]: MultiMonoFont::glyphs_per_row
+    pub fn rows_needed(&self) -> u32 {
+        match self.glyphs_per_row() {
+            0 => 0,
+            glyphs_per_row => {
+                let glyph_count = match self.offset_mapping {
+                    Some(offset_mapping) => offset_mapping.glyph_count(),
+                    None => self.glyph_mapping.glyph_count(),
+                };
+
+                (glyph_count as u32).div_ceil(glyphs_per_row)
+            }
+        }
+    }
+
+    /// Returns a subimage for the given area of the font image.
+    pub(crate) fn sub_image(&self, area: Rectangle) -> SubImage<'_, ImageRaw<'_, BinaryColor>> {
+        SubImage::new_unchecked(&self.image, area)
+    }
+
+    /// Returns the area of the glyph for the given character in the font image.
+    ///
+    /// Characters which aren't in the glyph mapping return the area of the replacement glyph. This
+    /// can be used to visualize and validate the glyph layout of the font image.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::ImageRaw, pixelcolor::BinaryColor, prelude::*, primitives::Rectangle,
+    /// };
+    /// use multi_mono_font::{mapping::StrGlyphMapping, CharSize, MultiMonoFont};
+    ///
+    /// const FONT: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::<BinaryColor>::new(&[0; 288], 96),
+    ///     &StrGlyphMapping::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 26),
+    ///     CharSize::new(6, 12),
+    ///     0,
+    ///     12,
+    /// );
+    ///
+    /// let size = Size::new(6, 12);
+    /// assert_eq!(FONT.glyph_atlas_rect('B'), Rectangle::new(Point::new(6, 0), size));
+    /// // the 16 glyphs of the first row are followed by the second row
+    /// assert_eq!(FONT.glyph_atlas_rect('R'), Rectangle::new(Point::new(6, 12), size));
+    /// ```
+    pub fn glyph_atlas_rect(&self, c: char) -> Rectangle {
         if self.character_size.width == 0
             || self.image.size().width < self.character_size.width as u32
         {
-            return SubImage::new_unchecked(&self.image, Rectangle::zero());
+            return Rectangle::zero();
         }
 
-        let glyphs_per_row = self.image.size().width / self.character_size.width as u32;
+        let glyphs_per_row = self.glyphs_per_row();
 
         // Char _code_ offset from first char, most often a space
         // E.g. first char = ' ' (32), target char = '!' (33), offset = 33 - 32 = 1
-        let glyph_index = self.glyph_mapping.index(c) as u32;
+        let glyph_index = self.mapping().index(c) as u32;
         let row = glyph_index / glyphs_per_row;
 
         // Top left corner of character, in pixels
         let char_x = (glyph_index - (row * glyphs_per_row)) * self.character_size.width as u32;
         let char_y = row * self.character_size.height as u32;
 
-        SubImage::new_unchecked(
-            &self.image,
-            Rectangle::new(
-                Point::new(char_x as i32, char_y as i32),
-                self.character_size.size(),
-            ),
+        Rectangle::new(
+            Point::new(char_x as i32, char_y as i32),
+            self.character_size.size(),
         )
     }
+
+    /// Returns the baseline of the glyph for the given character.
+    ///
+    /// The entry in [`baselines`] is used if the font has a per-glyph baseline table, otherwise
+    /// the common [`baseline`] is returned.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::ImageRaw, mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*,
+    ///     text::Text,
+    /// };
+    /// use multi_mono_font::{
+    ///     mapping::StrGlyphMapping, CharSize, MultiMonoFont, MultiMonoLineHeight,
+    ///     MultiMonoTextStyle,
+    /// };
+    ///
+    /// const FONT: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::new(&[0b1111_0000, 0b1111_0000], 4),
+    ///     &StrGlyphMapping::new("ab", 0),
+    ///     CharSize::new(2, 2),
+    ///     0,
+    ///     1,
+    /// )
+    /// .with_baselines(&[1, 0]);
+    /// assert_eq!(FONT.glyph_baseline('a'), 1);
+    /// assert_eq!(FONT.glyph_baseline('b'), 0);
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let mut display = MockDisplay::new();
+    /// Text::new("ab", Point::new(0, 2), style)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// display.assert_pattern(&[
+    ///     "    ", //
+    ///     "##  ", //
+    ///     "####", //
+    ///     "  ##", //
+    /// ]);
+    /// ```
+    ///
+    /// [`baselines`]: MultiMonoFont::baselines
+    /// [`baseline`]: MultiMonoFont::baseline
+    pub fn glyph_baseline(&self, c: char) -> ChSzTy {
+        match self.baselines {
+            Some(baselines) => baselines
+                .get(self.mapping().index(c))
+                .copied()
+                .unwrap_or(self.baseline),
+            None => self.baseline,
+        }
+    }
+
+    /// Returns if both fonts have the same glyphs and metrics.
+    ///
+    /// Unlike [`PartialEq`], which compares the glyph mappings by address, this compares the
+    /// glyph mapping data. Fonts which are defined separately from the same data are therefore
+    /// structurally equal. The [`membership_cache`] isn't compared.
+    ///
+    /// ```
+    /// use embedded_graphics::{image::ImageRaw, pixelcolor::BinaryColor};
+    /// use multi_mono_font::{mapping::StrGlyphMapping, CharSize, MultiMonoFont};
+    ///
+    /// const DATA: &[u8] = &[0b1010_0101, 0b0101_1010];
+    ///
+    /// const FONT_A: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::<BinaryColor>::new(DATA, 4),
+    ///     &StrGlyphMapping::new("ab", 0),
+    ///     CharSize::new(2, 2),
+    ///     0,
+    ///     1,
+    /// );
+    ///
+    /// const FONT_B: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::<BinaryColor>::new(DATA, 4),
+    ///     &StrGlyphMapping::new("ab", 0),
+    ///     CharSize::new(2, 2),
+    ///     0,
+    ///     1,
+    /// );
+    ///
+    /// assert!(FONT_A.structural_eq(&FONT_B));
+    ///
+    /// let other_mapping = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("xy", 0),
+    ///     ..FONT_B
+    /// };
+    /// assert!(!FONT_A.structural_eq(&other_mapping));
+    /// ```
+    ///
+    /// [`membership_cache`]: MultiMonoFont::membership_cache
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        self.image == other.image
+            && self.character_size == other.character_size
+            && self.character_spacing == other.character_spacing
+            && self.baseline == other.baseline
+            && self.baselines == other.baselines
+            && self.offset_mapping == other.offset_mapping
+            && self.glyph_mapping == other.glyph_mapping
+    }
 }
 
 impl PartialEq for MultiMonoFont<'_> {
@@ -96,6 +514,9 @@ impl PartialEq for MultiMonoFont<'_> {
             && self.character_size == other.character_size
             && self.character_spacing == other.character_spacing
             && self.baseline == other.baseline
+            && self.baselines == other.baselines
+            && self.membership_cache == other.membership_cache
+            && self.offset_mapping == other.offset_mapping
             && core::ptr::eq(self.glyph_mapping, other.glyph_mapping)
     }
 }
@@ -107,6 +528,9 @@ impl fmt::Debug for MultiMonoFont<'_> {
             .field("character_size", &self.character_size)
             .field("character_spacing", &self.character_spacing)
             .field("baseline", &self.baseline)
+            .field("baselines", &self.baselines)
+            .field("membership_cache", &self.membership_cache)
+            .field("offset_mapping", &self.offset_mapping)
             .field("glyph_mapping", &"?")
             .finish_non_exhaustive()
     }
@@ -117,22 +541,108 @@ impl ::defmt::Format for MultiMonoFont<'_> {
     fn format(&self, f: ::defmt::Formatter) {
         ::defmt::write!(
             f,
-            "MultiMonoFont {{ image: {}, character_size: {}, character_spacing: {}, baseline: {}, strikethrough: {}, underline: {}, .. }}",
+            "MultiMonoFont {{ image: {}, character_size: {}, character_spacing: {}, baseline: {}, glyph_mapping: ?, .. }}",
             &self.image,
             &self.character_size,
             &self.character_spacing,
             &self.baseline,
-            &self.strikethrough,
-            &self.underline,
-
         )
     }
 }
 
-const NULL_FONT: MultiMonoFont = MultiMonoFont {
-    image: ImageRaw::new(&[], 1),
-    character_size: CharSize::zero(),
-    character_spacing: 0,
-    baseline: 0,
-    glyph_mapping: &StrGlyphMapping::new("", 0),
-};
+/// Asserts at compile time that a font image is large enough for its glyph mapping.
+///
+/// The macro takes the raw image data, the image width in pixels, the character size and the
+/// glyph mapping, i.e. the values which are used to define a [`MultiMonoFont`]. Compilation fails
+/// if the image data doesn't consist of complete rows or if the image doesn't contain enough glyphs
+/// for all characters in the mapping. This catches, for example, a wrong image width.
+///
+/// ```
+/// use multi_mono_font::{const_assert_font, mapping::StrGlyphMapping, CharSize};
+///
+/// const_assert_font!(
+///     &[0; 288],
+///     96,
+///     CharSize::new(6, 12),
+///     StrGlyphMapping::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 26),
+/// );
+/// ```
+///
+/// Each of the following examples differs from the example above only in one value. The failed
+/// assertions are reported as errors in const evaluation (E0080).
+///
+/// An image width of 128 pixels instead of 96 pixels doesn't leave enough rows for all glyphs:
+///
+/// ```compile_fail,E0080
+/// use multi_mono_font::{const_assert_font, mapping::StrGlyphMapping, CharSize};
+///
+/// const_assert_font!(
+///     &[0; 288],
+///     128,
+///     CharSize::new(6, 12),
+///     StrGlyphMapping::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 26),
+/// );
+/// ```
+///
+/// 287 bytes of image data don't consist of complete rows of 12 bytes:
+///
+/// ```compile_fail,E0080
+/// use multi_mono_font::{const_assert_font, mapping::StrGlyphMapping, CharSize};
+///
+/// const_assert_font!(
+///     &[0; 287],
+///     96,
+///     CharSize::new(6, 12),
+///     StrGlyphMapping::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 26),
+/// );
+/// ```
+///
+/// Glyphs with a width of zero pixels are rejected:
+///
+/// ```compile_fail,E0080
+/// use multi_mono_font::{const_assert_font, mapping::StrGlyphMapping, CharSize};
+///
+/// const_assert_font!(
+///     &[0; 288],
+///     96,
+///     CharSize::new(0, 12),
+///     StrGlyphMapping::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 26),
+/// );
+/// ```
+#[macro_export]
+macro_rules! const_assert_font {
+    ($data:expr, $width:expr, $character_size:expr, $glyph_mapping:expr $(,)?) => {
+        const _: () = {
+            let data: &[u8] = $data;
+            let width: u32 = $width;
+            let character_size: $crate::CharSize = $character_size;
+            let glyph_mapping: $crate::mapping::StrGlyphMapping<'_> = $glyph_mapping;
+
+            let row_len = (width as usize).div_ceil(8);
+            assert!(
+                row_len > 0 && data.len() % row_len == 0,
+                "font image data doesn't consist of complete rows"
+            );
+            assert!(
+                character_size.width > 0 && character_size.height > 0,
+                "font character size is empty"
+            );
+
+            let height = (data.len() / row_len) as u32;
+            let glyphs_per_row = width / character_size.width as u32;
+            let glyph_rows = height / character_size.height as u32;
+            assert!(
+                (glyphs_per_row * glyph_rows) as usize >= glyph_mapping.glyph_count(),
+                "font image is too small for the glyph mapping"
+            );
+        };
+    };
+}
+
+const NULL_FONT: MultiMonoFont = MultiMonoFont::new(
+    ImageRaw::new(&[], 1),
+    &StrGlyphMapping::new("", 0),
+    CharSize::zero(),
+    0,
+    0,
+);
@@ -8,6 +8,9 @@
 //! Custom mappings can be defined in three different ways:
 //! * The [`StrGlyphMapping`] type can be used to specify a character mapping by encoding the
 //!   mapping as a string.
+//! * The [`OffsetGlyphMapping`] type can be used to specify a character mapping by a precomputed
+//!   table of code point blocks, which avoids scanning a mapping string for large fonts.
+//! * The [`GlyphMapping`] trait can be implemented for custom mapping types.
 //!
 //! # `StrGlyphMapping` encoding
 //!
@@ -43,6 +46,17 @@
 
 use core::ops::RangeInclusive;
 
+/// Mapping from characters to glyph indices.
+pub trait GlyphMapping {
+    /// Returns the glyph index for the given char.
+    ///
+    /// The replacement index is returned for characters that aren't in the mapping.
+    fn index(&self, c: char) -> usize;
+
+    /// Returns if the mapping contains the given char.
+    fn contains(&self, c: char) -> bool;
+}
+
 /// Glyph mapping stored as a UTF-8 string.
 ///
 /// See the [module-level documentation] for more details.
@@ -63,6 +77,62 @@ impl<'a> StrGlyphMapping<'a> {
         }
     }
 
+    /// Returns the encoded mapping string.
+    pub const fn data(&self) -> &'a str {
+        self.data
+    }
+
+    /// Returns the glyph index which is used for characters that aren't in the mapping.
+    ///
+    /// ```
+    /// use multi_mono_font::mapping::{StrGlyphMapping, ASCII};
+    ///
+    /// assert_eq!(ASCII.replacement_index(), '?' as usize - ' ' as usize);
+    ///
+    /// let mapping = StrGlyphMapping::new(ASCII.data(), ASCII.replacement_index());
+    /// assert_eq!(mapping, ASCII);
+    /// ```
+    pub const fn replacement_index(&self) -> usize {
+        self.replacement_index
+    }
+
+    /// Returns the number of glyphs in this mapping.
+    ///
+    /// ```
+    /// use multi_mono_font::mapping::{StrGlyphMapping, ASCII};
+    ///
+    /// assert_eq!(StrGlyphMapping::new("\0af\014", 0).glyph_count(), 10);
+    /// assert_eq!(ASCII.glyph_count(), 96);
+    /// ```
+    pub const fn glyph_count(&self) -> usize {
+        let bytes = self.data.as_bytes();
+        let mut index = 0;
+        let mut count = 0;
+
+        while index < bytes.len() {
+            let (c, next) = decode_char(bytes, index);
+            index = next;
+
+            if c == 0 {
+                if index >= bytes.len() {
+                    break;
+                }
+                let (start, next) = decode_char(bytes, index);
+                if next >= bytes.len() {
+                    break;
+                }
+                let (end, next) = decode_char(bytes, next);
+                index = next;
+
+                count += (end - start + 1) as usize;
+            } else {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
     /// Returns an iterator over the character ranges.
     pub fn ranges(&self) -> impl Iterator<Item = (usize, RangeInclusive<char>)> + '_ {
         let mut chars = self.data.chars();
@@ -112,20 +182,207 @@ impl<'a> StrGlyphMapping<'a> {
     }
 
     /// Returns if the mapping contains the given char.
+    ///
+    /// The characters are looked up by range, which doesn't iterate over every character in a
+    /// range.
+    ///
+    /// ```
+    /// use multi_mono_font::mapping::StrGlyphMapping;
+    ///
+    /// let cjk = StrGlyphMapping::new("\0\u{4e00}\u{9fff}", 0);
+    /// assert!(cjk.contains('\u{5b57}'));
+    /// assert!(cjk.contains('\u{9fff}'));
+    /// assert!(!cjk.contains('\u{4dff}'));
+    /// assert!(!cjk.contains('a'));
+    ///
+    /// let mapping = StrGlyphMapping::new("x\0ac", 0);
+    /// assert!(mapping.contains('x'));
+    /// assert!(mapping.contains('b'));
+    /// assert!(!mapping.contains('d'));
+    /// ```
     pub fn contains(&self, c: char) -> bool {
-        self.chars().any(|v| v == c)
+        self.ranges().any(|(_, range)| range.contains(&c))
     }
 
+    /// Returns the glyph index for the given char.
+    ///
+    /// The replacement index is returned for characters that aren't in the mapping.
+    ///
+    /// ```
+    /// use multi_mono_font::mapping::StrGlyphMapping;
+    ///
+    /// // the ranges `a..=c` and `d..=f` are adjacent
+    /// let mapping = StrGlyphMapping::new("\0ac\0dfx\0\u{4e00}\u{4e0f}", 3);
+    /// assert_eq!(mapping.index('a'), 0);
+    /// assert_eq!(mapping.index('c'), 2);
+    /// assert_eq!(mapping.index('d'), 3);
+    /// assert_eq!(mapping.index('f'), 5);
+    /// assert_eq!(mapping.index('x'), 6);
+    /// assert_eq!(mapping.index('\u{4e00}'), 7);
+    /// assert_eq!(mapping.index('\u{4e0f}'), 22);
+    ///
+    /// // characters outside of the ranges use the replacement index
+    /// assert_eq!(mapping.index('g'), 3);
+    /// assert_eq!(mapping.index('\u{4e10}'), 3);
+    ///
+    /// for (index, c) in mapping.chars().enumerate() {
+    ///     assert_eq!(mapping.index(c), index);
+    /// }
+    /// ```
     pub fn index(&self, c: char) -> usize {
-        // PERF: use ranges instead of chars iter
-        self.chars()
-            .enumerate()
-            .find(|(_, v)| c == *v)
-            .map(|(index, _)| index)
+        self.ranges()
+            .find(|(_, range)| range.contains(&c))
+            .map(|(index, range)| index + (c as usize - *range.start() as usize))
             .unwrap_or(self.replacement_index)
     }
 }
 
+impl GlyphMapping for StrGlyphMapping<'_> {
+    fn index(&self, c: char) -> usize {
+        StrGlyphMapping::index(self, c)
+    }
+
+    fn contains(&self, c: char) -> bool {
+        StrGlyphMapping::contains(self, c)
+    }
+}
+
+/// Glyph mapping stored as a table of code point blocks.
+///
+/// The table consists of `[first, last, index]` triples which map the contiguous block of code
+/// points `first..=last` to the consecutive glyph indices starting at `index`. The blocks must be
+/// sorted by code point and must not overlap, which allows lookups by binary search instead of
+/// scanning a mapping string. Only characters in the basic multilingual plane can be mapped.
+///
+/// ```
+/// use multi_mono_font::mapping::{GlyphMapping, OffsetGlyphMapping, StrGlyphMapping};
+///
+/// let str_mapping = StrGlyphMapping::new("\0\u{20}\u{7e}\0\u{4e00}\u{4e0f}\u{3002}", 0);
+/// let offset_mapping = OffsetGlyphMapping::new(
+///     &[0x20, 0x7e, 0, 0x3002, 0x3002, 111, 0x4e00, 0x4e0f, 95],
+///     0,
+/// );
+///
+/// assert_eq!(offset_mapping.glyph_count(), str_mapping.glyph_count());
+/// for c in ('\0'..='\u{5000}').chain(['\u{1f600}']) {
+///     assert_eq!(offset_mapping.index(c), str_mapping.index(c));
+///     assert_eq!(offset_mapping.contains(c), str_mapping.contains(c));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OffsetGlyphMapping<'a> {
+    table: &'a [u16],
+    replacement_index: usize,
+}
+
+impl<'a> OffsetGlyphMapping<'a> {
+    /// Creates a new glyph mapping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the table isn't a multiple of three.
+    pub const fn new(table: &'a [u16], replacement_index: usize) -> Self {
+        assert!(
+            table.len() % 3 == 0,
+            "offset table doesn't consist of [first, last, index] triples"
+        );
+
+        Self {
+            table,
+            replacement_index,
+        }
+    }
+
+    /// Returns the offset table.
+    pub const fn table(&self) -> &'a [u16] {
+        self.table
+    }
+
+    /// Returns the glyph index which is used for characters that aren't in the mapping.
+    pub const fn replacement_index(&self) -> usize {
+        self.replacement_index
+    }
+
+    /// Returns the number of glyphs in this mapping.
+    pub const fn glyph_count(&self) -> usize {
+        let mut block = 0;
+        let mut count = 0;
+
+        while block * 3 < self.table.len() {
+            count += (self.table[block * 3 + 1] - self.table[block * 3]) as usize + 1;
+            block += 1;
+        }
+
+        count
+    }
+
+    /// Returns an iterator over the characters in this mapping.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.table
+            .chunks_exact(3)
+            .flat_map(|block| block[0]..=block[1])
+            .filter_map(|c| char::from_u32(c.into()))
+    }
+
+    /// Returns the glyph index of the given char or `None` if the char isn't in the mapping.
+    fn find(&self, c: char) -> Option<usize> {
+        let c = u16::try_from(u32::from(c)).ok()?;
+
+        let mut low = 0;
+        let mut high = self.table.len() / 3;
+        while low < high {
+            let mid = (low + high) / 2;
+            let block = &self.table[mid * 3..mid * 3 + 3];
+
+            if c < block[0] {
+                high = mid;
+            } else if c > block[1] {
+                low = mid + 1;
+            } else {
+                return Some(block[2] as usize + (c - block[0]) as usize);
+            }
+        }
+
+        None
+    }
+}
+
+impl GlyphMapping for OffsetGlyphMapping<'_> {
+    fn index(&self, c: char) -> usize {
+        self.find(c).unwrap_or(self.replacement_index)
+    }
+
+    fn contains(&self, c: char) -> bool {
+        self.find(c).is_some()
+    }
+}
+
+/// Decodes the UTF-8 encoded character which starts at byte `index`.
+///
+/// Returns the code point and the index of the following character.
+const fn decode_char(bytes: &[u8], index: usize) -> (u32, usize) {
+    let b0 = bytes[index] as u32;
+
+    if b0 < 0x80 {
+        (b0, index + 1)
+    } else if b0 < 0xE0 {
+        let b1 = bytes[index + 1] as u32 & 0x3F;
+
+        ((b0 & 0x1F) << 6 | b1, index + 2)
+    } else if b0 < 0xF0 {
+        let b1 = bytes[index + 1] as u32 & 0x3F;
+        let b2 = bytes[index + 2] as u32 & 0x3F;
+
+        ((b0 & 0x0F) << 12 | b1 << 6 | b2, index + 3)
+    } else {
+        let b1 = bytes[index + 1] as u32 & 0x3F;
+        let b2 = bytes[index + 2] as u32 & 0x3F;
+        let b3 = bytes[index + 3] as u32 & 0x3F;
+
+        ((b0 & 0x07) << 18 | b1 << 12 | b2 << 6 | b3, index + 4)
+    }
+}
+
 macro_rules! impl_mapping {
     ($( $(#[$meta:meta])* ($enum_variant:ident, $constant:ident, $mapping:expr), )*) => {
         /// Mapping.
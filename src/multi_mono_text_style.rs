@@ -1,10 +1,11 @@
+use core::num::NonZeroU8;
+
 use embedded_graphics::{
     draw_target::DrawTarget,
     geometry::{Point, Size},
-    image::Image,
-    pixelcolor::{BinaryColor, PixelColor},
-    prelude::OriginDimensions,
-    primitives::Rectangle,
+    image::{GetPixel, Image},
+    pixelcolor::{BinaryColor, PixelColor, Rgb888, RgbColor},
+    primitives::{Line, PointsIter, Primitive, PrimitiveStyle, Rectangle},
     text::{
         renderer::{CharacterStyle, TextMetrics, TextRenderer},
         Baseline,
@@ -13,18 +14,86 @@ use embedded_graphics::{
 };
 
 use crate::{
-    char_size::CharSize,
-    draw_target::MultiMonoFontDrawTarget,
-    ChSzTy, MultiMonoFont,
+    draw_target::{
+        shrink_rect, Channel, ChannelDrawTarget, ColorMap, ColorMapDrawTarget, CoverageDrawTarget,
+        EdgeFadeDrawTarget, GlyphDrawTarget, MultiMonoFontDrawTarget, OpacityDrawTarget,
+        ShrinkDrawTarget, XorDrawTarget,
+    },
+    glyph_cache::GlyphCache,
+    wrap::{WrapIter, ZERO_WIDTH_SPACE},
+    ChSzTy, MultiMonoFont, Paragraph,
 };
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub enum MultiMonoLineHeight {
     Max,
     Min,
     Specify(ChSzTy),
 }
 
+/// Vertical alignment of glyphs inside the line.
+///
+/// The alignment only has an effect if a glyph is shorter than the line height.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum VerticalGlyphAlign {
+    /// Glyphs are aligned by the text baseline.
+    Baseline,
+
+    /// Glyphs are centered vertically inside the line.
+    CenterInLine,
+
+    /// Glyphs are aligned to the top edge of the line.
+    TopOfLine,
+}
+
+/// Font selection for characters which are contained in multiple fonts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum FontTieBreak {
+    /// The first font in the font list is used.
+    FirstInSlice,
+
+    /// The font with the largest glyph cell is used.
+    ///
+    /// The first of these fonts is used if multiple fonts have the same cell size.
+    LargestCell,
+
+    /// The font with the smallest glyph cell is used.
+    ///
+    /// The first of these fonts is used if multiple fonts have the same cell size.
+    SmallestCell,
+}
+
+/// Pixels of the glyph cells which are drawn by a style.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+enum ColorPass {
+    /// Glyph and background pixels are drawn.
+    All,
+
+    /// Only glyph pixels are drawn, see [`MultiMonoTextStyle::foreground_only`].
+    Foreground,
+
+    /// Glyph and background pixels are drawn in the background color, see
+    /// [`MultiMonoTextStyle::background_only`].
+    Background,
+}
+
+/// Blend mode of the text pixels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum BlendMode {
+    /// The drawn pixels replace the pixels of the draw target.
+    Replace,
+
+    /// The drawn pixels are XORed with the pixels of the draw target.
+    ///
+    /// Drawing the same text twice restores the original content, which is useful for cursors.
+    Xor,
+}
+
 const fn get_line_height<'a>(
     fonts_height: MultiMonoLineHeight,
     fonts: &'a [&'a MultiMonoFont<'a>],
@@ -57,6 +126,135 @@ const fn get_line_height<'a>(
     }
 }
 
+/// Returns the width of a Unicode space character in halves of the width of a normal space.
+///
+/// Returns `None` for characters which aren't one of the supported space variants.
+const fn space_variant_width(c: char) -> Option<u32> {
+    match c {
+        '\u{00A0}' | '\u{2002}' => Some(2),
+        '\u{2003}' => Some(4),
+        '\u{2009}' => Some(1),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the character belongs to a right-to-left script.
+///
+/// Only the Hebrew, Arabic, Syriac, Thaana and N'Ko blocks and the Hebrew and Arabic presentation
+/// forms are classified as right-to-left.
+const fn is_rtl(c: char) -> bool {
+    matches!(c, '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}')
+}
+
+/// Returns the characters of `text` and their byte offsets in visual order.
+///
+/// If `bidi_auto` is enabled, runs of right-to-left characters are reversed. Neutral characters,
+/// like spaces and punctuation, always end a run and are kept in logical order. Occurrences of
+/// the strings in `ligatures` are replaced by the corresponding ligature character.
+fn visual_order<'t>(
+    text: &'t str,
+    bidi_auto: bool,
+    ligatures: &'t [(&'t str, char)],
+) -> impl Iterator<Item = (usize, char)> + 't {
+    let mut offset = 0;
+    let mut run_offset = 0;
+    let mut run = "".char_indices().rev();
+
+    core::iter::from_fn(move || {
+        if let Some((index, c)) = run.next() {
+            return Some((run_offset + index, c));
+        }
+
+        let rest = &text[offset..];
+        let c = rest.chars().next()?;
+        if bidi_auto && is_rtl(c) {
+            let len = rest.find(|c| !is_rtl(c)).unwrap_or(rest.len());
+            run = rest[..len].char_indices().rev();
+            run_offset = offset;
+            offset += len;
+
+            run.next().map(|(index, c)| (run_offset + index, c))
+        } else if let Some((ligature, ligature_char)) = ligatures
+            .iter()
+            .find(|(ligature, _)| !ligature.is_empty() && rest.starts_with(ligature))
+        {
+            offset += ligature.len();
+
+            Some((offset - ligature.len(), *ligature_char))
+        } else {
+            offset += c.len_utf8();
+
+            Some((offset - c.len_utf8(), c))
+        }
+    })
+}
+
+/// Metrics of a single glyph, returned by [`MultiMonoTextStyle::glyph_metrics`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct GlyphMetrics {
+    /// Horizontal advance, including the character spacing.
+    pub advance: u32,
+
+    /// Size of the glyph.
+    pub size: Size,
+
+    /// Offset of the baseline from the top edge of the glyph.
+    pub baseline: u32,
+}
+
+/// Resumable position for [`MultiMonoTextStyle::draw_string_chunked`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct DrawCursor {
+    /// Byte offset of the next character to draw.
+    pub offset: usize,
+
+    /// Pen position of the next character to draw.
+    pub position: Point,
+}
+
+/// Returns the smallest rectangle which contains both rectangles.
+///
+/// Zero sized rectangles are ignored.
+pub(crate) fn envelope(a: &Rectangle, b: &Rectangle) -> Rectangle {
+    match (a.bottom_right(), b.bottom_right()) {
+        (Some(a_bottom_right), Some(b_bottom_right)) => Rectangle::with_corners(
+            a.top_left.component_min(b.top_left),
+            a_bottom_right.component_max(b_bottom_right),
+        ),
+        (Some(_), None) => *a,
+        _ => *b,
+    }
+}
+
+impl DrawCursor {
+    /// Creates a cursor at the start of a string.
+    pub const fn new(position: Point) -> Self {
+        Self {
+            offset: 0,
+            position,
+        }
+    }
+
+    /// Returns `true` if all characters of `text` were drawn.
+    pub const fn is_done(&self, text: &str) -> bool {
+        self.offset >= text.len()
+    }
+}
+
+/// Position of a string in its line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LineOffset {
+    /// The string starts the line, the first glyph indent is applied.
+    Start,
+
+    /// The string continues a line at the pen position `x`, relative to the start of the line
+    /// and including the first glyph indent. `leading` is `true` if only tabs precede the string
+    /// on its line.
+    Continue { x: u32, leading: bool },
+}
+
 /// Style properties for text using a monospaced font.
 ///
 /// A `MultiMonoTextStyle` can be applied to a [`Text`] object to define how the text is drawn.
@@ -70,8 +268,9 @@ const fn get_line_height<'a>(
 /// [`non_exhaustive`]: https://blog.rust-lang.org/2019/12/19/Rust-1.40.0.html#[non_exhaustive]-structs,-enums,-and-variants
 /// [`new`]: MultiMonoTextStyle::new()
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 #[non_exhaustive]
+// the lint is unknown to compilers before Rust 1.85
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
 pub struct MultiMonoTextStyle<'a, C> {
     /// Text color.
     pub text_color: C,
@@ -84,6 +283,224 @@ pub struct MultiMonoTextStyle<'a, C> {
 
     ///Line height
     pub line_height: ChSzTy,
+
+    /// Use the font of the surrounding text for spaces.
+    ///
+    /// If enabled, a space advances by the width of the font of the preceding glyph, or of the
+    /// following glyph if the space is at the start of the string, instead of the width of the
+    /// font which contains the space glyph.
+    pub space_follows_context: bool,
+
+    /// Number of spaces a tab character is expanded to.
+    ///
+    /// If set, each `\t` is drawn and measured as this number of space glyphs. Otherwise tabs are
+    /// treated like any other character.
+    pub tab_expansion: Option<u8>,
+
+    /// Distance between tab stops.
+    ///
+    /// If set, each `\t` advances the position to the next multiple of this width, relative to
+    /// the start of the line, and is drawn as an empty cell. This setting is ignored if
+    /// [`tab_expansion`] is set.
+    ///
+    /// [`tab_expansion`]: MultiMonoTextStyle::tab_expansion
+    pub tab_stops: Option<u32>,
+
+    /// Advance of tabs at the start of a line.
+    ///
+    /// If set, each `\t` before the first other character of a line advances by this width and is
+    /// drawn as an empty cell, independent of the tab stops. This makes it possible
+    /// to indent code by tabs. This setting is ignored if [`tab_expansion`] is set.
+    ///
+    /// [`tab_expansion`]: MultiMonoTextStyle::tab_expansion
+    pub indent_tab_width: Option<u32>,
+
+    /// Replacement function for missing glyphs.
+    ///
+    /// If set, characters which aren't contained in any font are passed to this function and
+    /// the returned character is drawn instead.
+    pub replacement_fn: Option<fn(char) -> char>,
+
+    /// Replacement string for missing glyphs.
+    ///
+    /// If set, characters which aren't contained in any font, also after the
+    /// [`replacement_fn`] was applied, are drawn and measured as this string.
+    ///
+    /// [`replacement_fn`]: MultiMonoTextStyle::replacement_fn
+    pub replacement_str: Option<&'a str>,
+
+    /// Replacement font.
+    ///
+    /// If set, characters which aren't contained in any font are drawn with the replacement glyph
+    /// of this font instead of the replacement glyph of the first font.
+    pub replacement_font: Option<&'a MultiMonoFont<'a>>,
+
+    /// Minimum glyph advance.
+    ///
+    /// Glyphs narrower than this width are centered in a cell of the minimum width. The character
+    /// spacing is added after the cell.
+    pub min_advance: Option<ChSzTy>,
+
+    /// Column spacing.
+    ///
+    /// Additional spacing between glyph cells, which is only used if [`min_advance`] is set. This
+    /// adds a gutter between the columns of grid layouts without changing the character spacing of
+    /// the fonts.
+    ///
+    /// [`min_advance`]: MultiMonoTextStyle::min_advance
+    pub column_spacing: ChSzTy,
+
+    /// Letter spacing.
+    ///
+    /// Added to the advance of each glyph, in addition to the character spacing of the fonts.
+    /// Negative values tighten the text, the advance of a glyph is at least one pixel.
+    pub letter_spacing: i8,
+
+    /// Advance of replaced glyphs.
+    ///
+    /// If set, characters which aren't contained in the font used to draw them, and are therefore
+    /// drawn with the replacement glyph, advance by this width, including the character spacing.
+    pub replacement_advance: Option<ChSzTy>,
+
+    /// Reverse runs of right-to-left characters.
+    ///
+    /// This is a lightweight replacement for the Unicode bidirectional algorithm: contiguous runs
+    /// of characters from right-to-left scripts are drawn in reverse order, everything else is
+    /// drawn left-to-right. Neutral characters, like spaces, aren't part of right-to-left runs
+    /// and no mirroring of brackets is done.
+    pub bidi_auto: bool,
+
+    /// Reverse the order of all characters.
+    ///
+    /// The characters are laid out from left to right in reverse order, which is useful for
+    /// mirror mounted displays. [`bidi_auto`] and [`ligatures`] are ignored if this is set.
+    ///
+    /// [`bidi_auto`]: MultiMonoTextStyle::bidi_auto
+    /// [`ligatures`]: MultiMonoTextStyle::ligatures
+    pub reverse_chars: bool,
+
+    /// Ligatures.
+    ///
+    /// Each occurrence of a string in this list is replaced by the corresponding character before
+    /// the text is drawn or measured. Ligatures are matched greedily in list order.
+    pub ligatures: &'a [(&'a str, char)],
+
+    /// Ignored characters.
+    ///
+    /// Characters in this list are removed from the text before it is drawn or measured. Unlike
+    /// characters without a glyph they aren't replaced and don't advance the position.
+    pub ignore_chars: &'a [char],
+
+    /// Clipped glyph rows.
+    ///
+    /// If set, only the glyph rows in the half-open range `start..end` are drawn. The other rows
+    /// of the glyph cell are filled with the background color, if one is set. The advance isn't
+    /// affected.
+    pub clip_rows: Option<(ChSzTy, ChSzTy)>,
+
+    /// Cache the glyph areas of recently drawn characters.
+    ///
+    /// If enabled, the positions of the last few glyphs in the font images are cached while a
+    /// string is drawn. This speeds up drawing text with repeated characters, especially for fonts
+    /// with long glyph mappings.
+    pub glyph_cache: bool,
+
+    /// Downscaling factor.
+    ///
+    /// If larger than one, only every n-th pixel column and row of the text is drawn, which
+    /// shrinks the text by this factor. The advance, the measured size and the line height are
+    /// divided by the factor. This is useful to preview large fonts at a small size.
+    pub shrink: NonZeroU8,
+
+    /// Foreground pattern.
+    ///
+    /// If not empty, glyph pixels are drawn in the colors of this pattern instead of the text
+    /// color. The pattern is sampled by the y coordinate of each pixel, which draws horizontal
+    /// stripes that repeat every `fg_pattern.len()` rows.
+    pub fg_pattern: &'a [C],
+
+    /// Hollow glyphs.
+    ///
+    /// If `true`, only the edge pixels of each glyph are drawn. Edge pixels are glyph pixels
+    /// which have a horizontal or vertical neighbor outside the glyph.
+    pub hollow: bool,
+
+    /// Upside-down characters.
+    ///
+    /// The glyphs of characters in this list are rotated by 180°, which is useful for split-flap
+    /// or novelty displays. The advance isn't affected.
+    pub flip_chars: &'a [char],
+
+    /// Vertical alignment of glyphs inside the line.
+    pub vertical_align: VerticalGlyphAlign,
+
+    /// Round the middle baseline to the nearest pixel.
+    ///
+    /// The middle of a glyph or line of height `h` is `(h - 1) / 2` pixels below the top edge,
+    /// which is rounded down by default and therefore biased upward for even heights. If `true`,
+    /// the middle is rounded to the nearest pixel instead, which centers [`Baseline::Middle`]
+    /// text better.
+    pub middle_round_nearest: bool,
+
+    /// Indent before the first glyph.
+    ///
+    /// Every drawn string starts this many pixels to the right of the pen position. The indent is
+    /// filled with the background color and is included in the measured width.
+    pub first_glyph_indent: u32,
+
+    /// Diagnostic function for clipped text.
+    ///
+    /// If set, this function is called with the bounding box of a drawn string if the string
+    /// isn't completely inside the bounding box of the draw target. The check is only done in
+    /// builds with debug assertions and helps to find layout bugs which are hidden by clipping.
+    pub clip_warning: Option<fn(Rectangle)>,
+
+    /// Fallback style.
+    ///
+    /// If set, characters which aren't contained in any font of this style, but in a font of the
+    /// fallback style, are drawn and measured with that font and drawn with the colors of the
+    /// fallback style. All other options of this style, like the line height, the tab stops and
+    /// the first glyph indent, are used for the whole text.
+    pub fallback: Option<&'a MultiMonoTextStyle<'a, C>>,
+
+    /// Pixel grid of the text origin.
+    ///
+    /// If larger than 1, the position of drawn and measured strings is rounded down to a multiple
+    /// of this value in both directions. Snapping the origin to the scale factor of a scaled draw
+    /// target keeps the glyph pixels aligned to the scaled pixels.
+    pub grid_snap: ChSzTy,
+
+    /// Background colors of the fonts.
+    ///
+    /// The entry at index `i` overrides the background color for glyphs from `fonts[i]`. Fonts
+    /// without an entry, or with a `None` entry, use the background color of the style.
+    pub font_background_colors: &'a [Option<C>],
+
+    /// Character spacing multipliers of the fonts.
+    ///
+    /// The entry at index `i` multiplies the character spacing of `fonts[i]`, which makes it
+    /// possible to use more spacing for wide scripts, like CJK, than for ASCII in a single style.
+    /// Fonts without an entry use their character spacing unchanged.
+    pub font_spacing_scales: &'a [u8],
+
+    /// Combining characters.
+    ///
+    /// Characters in this list don't advance the position and are drawn over the glyph cell of
+    /// the preceding character, which makes it possible to stack marks on base characters. Only
+    /// the glyph pixels of combining characters are drawn, the background isn't filled.
+    pub combining_chars: &'a [char],
+
+    /// Vertical offset of combining characters.
+    ///
+    /// Combining characters are drawn this many pixels below their regular position, negative
+    /// values move them up.
+    pub combining_y_offset: i8,
+
+    /// Font selection for characters which are contained in multiple fonts.
+    pub font_tie_break: FontTieBreak,
+
+    /// Pixels of the glyph cells which are drawn.
+    color_pass: ColorPass,
 }
 
 impl<'a, C> MultiMonoTextStyle<'a, C>
@@ -101,308 +518,3955 @@ where
             .build()
     }
 
-    fn get_font_info(&self, c: char) -> &MultiMonoFont<'a> {
-        for font in self.fonts {
-            if font.glyph_mapping.contains(c) {
-                return font;
-            }
+    /// Returns a copy of this style which only draws the glyph foreground.
+    ///
+    /// The background color and the background colors of the fonts are removed, which makes it
+    /// possible to draw all glyphs after the backgrounds were drawn by [`background_only`] in a
+    /// separate pass.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::ImageRaw, mock_display::MockDisplay, pixelcolor::Rgb565, prelude::*,
+    ///     primitives::PointsIter, text::Text,
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, mapping::StrGlyphMapping, CharSize, MultiMonoFont, MultiMonoLineHeight,
+    ///     MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// fn assert_two_pass(text: &str, style: MultiMonoTextStyle<'_, Rgb565>, background_color: Rgb565) {
+    ///     let mut single_pass = MockDisplay::new();
+    ///     Text::new(text, Point::new(0, 6), style)
+    ///         .draw(&mut single_pass)
+    ///         .unwrap();
+    ///
+    ///     // the background pass only draws the background color
+    ///     let mut background = MockDisplay::new();
+    ///     Text::new(text, Point::new(0, 6), style.background_only().unwrap())
+    ///         .draw(&mut background)
+    ///         .unwrap();
+    ///     let area = background.affected_area();
+    ///     assert!(area
+    ///         .points()
+    ///         .all(|point| background.get_pixel(point) == Some(background_color)));
+    ///
+    ///     // the foreground pass doesn't draw the background color
+    ///     let mut foreground = MockDisplay::new();
+    ///     Text::new(text, Point::new(0, 6), style.foreground_only())
+    ///         .draw(&mut foreground)
+    ///         .unwrap();
+    ///     let area = foreground.affected_area();
+    ///     assert!(area
+    ///         .points()
+    ///         .all(|point| foreground.get_pixel(point) != Some(background_color)));
+    ///
+    ///     let mut two_pass = background;
+    ///     two_pass.set_allow_overdraw(true);
+    ///     Text::new(text, Point::new(0, 6), style.foreground_only())
+    ///         .draw(&mut two_pass)
+    ///         .unwrap();
+    ///
+    ///     two_pass.assert_eq(&single_pass);
+    /// }
+    ///
+    /// let builder = MultiMonoTextStyleBuilder::new(Rgb565::WHITE)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max);
+    ///
+    /// assert_two_pass("Hi", builder.background_color(Rgb565::BLACK).build(), Rgb565::BLACK);
+    ///
+    /// // foreground pattern
+    /// let style = builder
+    ///     .background_color(Rgb565::BLACK)
+    ///     .fg_pattern(&[Rgb565::GREEN, Rgb565::RED])
+    ///     .build();
+    /// assert_two_pass("Hi", style, Rgb565::BLACK);
+    ///
+    /// // per-font background colors
+    /// let style = builder.font_background_colors(&[Some(Rgb565::BLUE)]).build();
+    /// assert_two_pass("Hi", style, Rgb565::BLUE);
+    ///
+    /// // glyphs of a fallback style with a background color
+    /// const EMOJI: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::new(&[0b1000_0000, 0b0100_0000], 2),
+    ///     &StrGlyphMapping::new("\u{263A}", 0),
+    ///     CharSize::new(2, 2),
+    ///     0,
+    ///     1,
+    /// );
+    /// let emoji = MultiMonoTextStyleBuilder::new(Rgb565::RED)
+    ///     .font(&[&EMOJI], MultiMonoLineHeight::Max)
+    ///     .background_color(Rgb565::BLACK)
+    ///     .build();
+    /// let style = builder.build().with_fallback(&emoji);
+    /// assert_two_pass("\u{263A}", style, Rgb565::BLACK);
+    /// ```
+    ///
+    /// [`background_only`]: MultiMonoTextStyle::background_only()
+    pub const fn foreground_only(&self) -> Self {
+        Self {
+            background_color: None,
+            color_pass: ColorPass::Foreground,
+            ..*self
+        }
+    }
+
+    /// Returns a copy of this style which only draws the glyph background.
+    ///
+    /// The whole glyph cell is filled with the background color, or the background color of its
+    /// font, including the pixels which would normally be drawn in the text color. Glyphs without a
+    /// background color aren't drawn. Returns `None` if neither the style, nor its fonts, nor the
+    /// fallback style have a background color, because there is nothing to draw in that case.
+    pub const fn background_only(&self) -> Option<Self> {
+        if !self.has_background() {
+            return None;
         }
 
-        self.fonts[0]
+        let text_color = match self.background_color {
+            Some(background_color) => background_color,
+            None => self.text_color,
+        };
+
+        Some(Self {
+            text_color,
+            fg_pattern: &[],
+            color_pass: ColorPass::Background,
+            ..*self
+        })
     }
 
-    fn draw_string_binary<D>(
-        &self,
-        text: &str,
-        position: Point,
-        baseline: Baseline,
-        mut target: D,
-    ) -> Result<Point, D::Error>
-    where
-        D: DrawTarget<Color = BinaryColor>,
-    {
-        let mut next_pos = position;
-        let mut draw_pos;
-
-        for c in text.chars() {
-            let font = self.get_font_info(c);
-            let glyph = font.glyph(c);
-            draw_pos = next_pos - Point::new(0, self.baseline_offset(baseline, font));
-            Image::new(&glyph, draw_pos).draw(&mut target)?;
-            next_pos.x += font.character_size.width as i32;
-            if font.character_spacing > 0 {
-                draw_pos.x += font.character_size.width as i32;
-                if self.background_color.is_some() {
-                    target.fill_solid(
-                        &Rectangle::new(
-                            draw_pos,
-                            CharSize::new(font.character_spacing, font.character_size.height).size(),
-                        ),
-                        BinaryColor::Off,
-                    )?;
-                }
-                next_pos.x += font.character_spacing as i32;
+    /// Returns `true` if the style, one of its fonts or the fallback style has a background color.
+    const fn has_background(&self) -> bool {
+        if self.background_color.is_some() {
+            return true;
+        }
+
+        let mut index = 0;
+        while index < self.font_background_colors.len() {
+            if self.font_background_colors[index].is_some() {
+                return true;
             }
+            index += 1;
         }
 
-        Ok(next_pos)
+        match self.fallback {
+            Some(fallback) => fallback.has_background(),
+            None => false,
+        }
     }
 
-    /// Returns the vertical offset between the line position and the top edge of the bounding box.
-    fn baseline_offset(&self, baseline: Baseline, font: &MultiMonoFont<'a>) -> i32 {
-        match baseline {
-            Baseline::Top => 0,
-            Baseline::Bottom => font.character_size.height.saturating_sub(1) as i32,
-            Baseline::Middle => (font.character_size.height.saturating_sub(1) / 2) as i32,
-            Baseline::Alphabetic => font.baseline as i32,
+    /// Returns a copy of this style which uses `fallback` for characters it can't draw.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::ImageRaw,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, mapping::StrGlyphMapping, CharSize, MultiMonoFont,
+    ///     MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const EMOJI: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::new(&[0b1100_0000, 0b1100_0000], 2),
+    ///     &StrGlyphMapping::new("\u{263A}", 0),
+    ///     CharSize::new(2, 2),
+    ///     0,
+    ///     1,
+    /// );
+    ///
+    /// let emoji = MultiMonoTextStyle::new(&[&EMOJI], MultiMonoLineHeight::Max, Rgb565::RED);
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, Rgb565::GREEN)
+    ///     .with_fallback(&emoji);
+    ///
+    /// let text = "A\u{263A}";
+    /// let metrics = style.measure_string(text, Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size, Size::new(8, 9));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline(text, Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// // the emoji is drawn with the font and color of the fallback style
+    /// assert_eq!(display.get_pixel(Point::new(6, 0)), Some(Rgb565::RED));
+    /// assert_eq!(display.get_pixel(Point::new(7, 1)), Some(Rgb565::RED));
+    ///
+    /// // the fallback glyphs are laid out like all other glyphs
+    /// assert!(style.char_offsets("a\u{263A}b").eq([6, 8, 14]));
+    ///
+    /// let indented = MultiMonoTextStyleBuilder::from(&style)
+    ///     .first_glyph_indent(5)
+    ///     .build();
+    /// let metrics = indented.measure_string("a\u{263A}b", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size.width, 19);
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let next = indented
+    ///     .draw_string("a\u{263A}b", Point::zero(), Baseline::Top, &mut display)
+    ///     .unwrap();
+    /// assert_eq!(next, Point::new(19, 0));
+    /// ```
+    pub const fn with_fallback(&self, fallback: &'a MultiMonoTextStyle<'a, C>) -> Self {
+        Self {
+            fallback: Some(fallback),
+            ..*self
         }
     }
-}
 
-impl<C> TextRenderer for MultiMonoTextStyle<'_, C>
-where
-    C: PixelColor,
-{
-    type Color = C;
+    /// Returns the text color, the background color and the foreground pattern of glyphs which
+    /// are drawn with the given font.
+    ///
+    /// Glyphs from the fonts of the fallback style are drawn with the colors of the fallback style,
+    /// but only the pixels selected by this style are drawn. Returns `None` if the glyphs aren't
+    /// drawn at all.
+    fn glyph_colors(&self, font: &MultiMonoFont<'a>) -> Option<(C, Option<C>, &'a [C])> {
+        let (text_color, background_color, fg_pattern) = self.font_colors(font);
 
-    fn draw_string<D>(
-        &self,
-        text: &str,
-        position: Point,
-        baseline: Baseline,
-        target: &mut D,
-    ) -> Result<Point, D::Error>
-    where
-        D: DrawTarget<Color = Self::Color>,
-    {
-        self.draw_string_binary(
-            text,
-            position,
-            baseline,
-            MultiMonoFontDrawTarget::new(target, self.text_color, self.background_color),
-        )
+        match self.color_pass {
+            ColorPass::All => Some((text_color, background_color, fg_pattern)),
+            ColorPass::Foreground => Some((text_color, None, fg_pattern)),
+            ColorPass::Background => background_color
+                .map(|background_color| (background_color, Some(background_color), &[][..])),
+        }
     }
 
-    fn draw_whitespace<D>(
-        &self,
-        width: u32,
-        position: Point,
-        baseline: Baseline,
-        target: &mut D,
-    ) -> Result<Point, D::Error>
-    where
-        D: DrawTarget<Color = Self::Color>,
-    {
-        let (offet_y, height) = match baseline {
-            Baseline::Top => (0, self.line_height),
-            Baseline::Bottom => (self.line_height.saturating_sub(1) as i32, self.line_height),
-            Baseline::Middle => (
-                (self.line_height.saturating_sub(1) / 2) as i32,
-                self.line_height,
-            ),
-            Baseline::Alphabetic => (
-                self.fonts[0].baseline as i32,
-                self.fonts[0].character_size.height,
-            ),
-        };
-        let position = position - Point::new(0, offet_y);
+    /// Returns the colors of glyphs which are drawn with the given font, ignoring the color pass.
+    fn font_colors(&self, font: &MultiMonoFont<'a>) -> (C, Option<C>, &'a [C]) {
+        match (self.font_index(font), self.fallback) {
+            (None, Some(fallback)) if fallback.uses_font(font) => fallback.font_colors(font),
+            (index, _) => {
+                let background_color = index
+                    .and_then(|index| self.font_background_colors.get(index).copied().flatten())
+                    .or(self.background_color);
 
-        if width != 0 {
-            if let Some(background_color) = self.background_color {
-                target.fill_solid(
-                    &Rectangle::new(position, Size::new(width, height as u32)),
-                    background_color,
-                )?;
+                (self.text_color, background_color, self.fg_pattern)
             }
         }
+    }
 
-        Ok(position + Point::new(width as i32, offet_y))
+    /// Returns `true` if the font is one of the fonts of this style or of the fallback style.
+    fn uses_font(&self, font: &MultiMonoFont<'a>) -> bool {
+        self.font_index(font).is_some()
+            || self
+                .fallback
+                .is_some_and(|fallback| fallback.uses_font(font))
     }
 
-    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
-        let mut bb_width = 0;
-        let mut bb_height = 0;
-        let mut baseline_max = 0;
-        let mut font = self.fonts[0];
-        for c in text.chars() {
-            font = self.get_font_info(c);
-            bb_width += (font.character_size.width + font.character_spacing) as u32;
-            bb_height = bb_height.max(font.character_size.height as u32);
+    /// Returns `true` if drawing `text` with this style would produce any output.
+    ///
+    /// A style is never fully transparent, because the text color is always drawn. Nothing is
+    /// drawn if `text` is empty, if all fonts of the style are empty, e.g. because the style was
+    /// built with an empty font list, or if `text` only contains whitespace and no background
+    /// color is set for the fonts of its characters. Whitespace glyphs are assumed to be blank.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// assert!(style.will_draw("Hi"));
+    /// assert!(!style.will_draw(""));
+    ///
+    /// // whitespace is only drawn as background
+    /// assert!(!style.will_draw(" \t "));
+    /// let with_background = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .background_color(BinaryColor::Off)
+    ///     .build();
+    /// assert!(with_background.will_draw(" \t "));
+    ///
+    /// let empty = MultiMonoTextStyle::new(&[], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// assert!(!empty.will_draw("Hi"));
+    /// ```
+    pub fn will_draw(&self, text: &str) -> bool {
+        let is_empty = |font: &MultiMonoFont<'_>| {
+            font.character_size.width == 0 || font.character_size.height == 0
+        };
 
-            baseline_max = baseline_max.max(self.baseline_offset(baseline, font));
+        if self.fonts.iter().all(|font| is_empty(font)) {
+            return false;
         }
-        bb_width = bb_width.saturating_sub(font.character_spacing as u32);
-
-        let bb_size = Size::new(bb_width, bb_height);
 
-        let bb_position = position - Point::new(0, baseline_max);
-        TextMetrics {
-            bounding_box: Rectangle::new(bb_position, bb_size),
-            next_position: position + bb_size.x_axis(),
-        }
+        self.layout_chars(text).any(|(c, font)| {
+            !is_empty(font)
+                && self
+                    .glyph_colors(font)
+                    .is_some_and(|(_, background_color, _)| {
+                        !c.is_whitespace() || background_color.is_some()
+                    })
+        })
     }
 
-    fn line_height(&self) -> u32 {
-        self.line_height as u32
+    /// Returns the first character of `required` which isn't contained in any font.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// assert_eq!(style.first_uncovered("Hello"), None);
+    /// assert_eq!(style.first_uncovered("Gr\u{FC}\u{DF}e"), Some('\u{FC}'));
+    /// ```
+    pub fn first_uncovered(&self, required: &str) -> Option<char> {
+        required.chars().find(|&c| self.find_font(c).is_none())
     }
-}
 
-impl<C> CharacterStyle for MultiMonoTextStyle<'_, C>
-where
-    C: PixelColor,
-{
-    type Color = C;
+    /// Checks that all characters of `required` are contained in a font.
+    ///
+    /// Returns the missing characters in the order of their first occurrence.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// assert_eq!(style.validate_coverage("Hello"), Ok(()));
+    /// assert_eq!(
+    ///     style.validate_coverage("Gr\u{FC}\u{DF}e \u{FC}ber"),
+    ///     Err(vec!['\u{FC}', '\u{DF}'])
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn validate_coverage(&self, required: &str) -> Result<(), alloc::vec::Vec<char>> {
+        let mut missing = alloc::vec::Vec::new();
+        for c in required.chars() {
+            if self.find_font(c).is_none() && !missing.contains(&c) {
+                missing.push(c);
+            }
+        }
 
-    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
-        if let Some(color) = text_color {
-            self.text_color = color;
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
         }
     }
 
-    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
-        self.background_color = background_color;
+    /// Returns the index of the largest style for which the wrapped `text` fits into `rect`.
+    ///
+    /// The text is wrapped to the width of `rect` like a [`Paragraph`]. The largest style is the
+    /// style with the largest line height, ties are resolved in favor of the earlier style.
+    /// Returns `None` if the text doesn't fit into `rect` with any of the styles.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, primitives::Rectangle};
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_10X20, FONT_4X6, FONT_6X10},
+    ///     MultiMonoLineHeight, MultiMonoTextStyle,
+    /// };
+    ///
+    /// let small = MultiMonoTextStyle::new(&[&FONT_4X6], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let medium = MultiMonoTextStyle::new(&[&FONT_6X10], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let large = MultiMonoTextStyle::new(&[&FONT_10X20], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let styles = [&small, &large, &medium];
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(40, 24));
+    /// assert_eq!(MultiMonoTextStyle::best_fit_box("Hello world", rect, &styles), Some(2));
+    ///
+    /// let rect = Rectangle::new(Point::zero(), Size::new(40, 4));
+    /// assert_eq!(MultiMonoTextStyle::best_fit_box("Hello world", rect, &styles), None);
+    /// ```
+    pub fn best_fit_box(
+        text: &str,
+        rect: Rectangle,
+        styles: &[&MultiMonoTextStyle<'a, C>],
+    ) -> Option<usize> {
+        styles
+            .iter()
+            .enumerate()
+            .filter(|&(_, &style)| {
+                let size = Paragraph::new(text, rect.top_left, rect.size.width, *style).measure();
+                size.width <= rect.size.width && size.height <= rect.size.height
+            })
+            .fold(
+                None,
+                |best: Option<(usize, ChSzTy)>, (index, style)| match best {
+                    Some((_, line_height)) if line_height >= style.line_height => best,
+                    _ => Some((index, style.line_height)),
+                },
+            )
+            .map(|(index, _)| index)
     }
-}
 
-/// Text style builder for monospaced fonts.
-///
-/// Use this builder to create [`MultiMonoTextStyle`]s for [`Text`].
-///
-/// # Examples
-///
-/// ## Render yellow text on a blue background
-///
-/// This uses the [`FONT_6X9`] font, but [other fonts] can also be used.
-///
-/// ```rust
-/// use multi_mono_font::{ascii::FONT_6X9, MultiMonoTextStyle, MultiMonoTextStyleBuilder};
-/// use embedded_graphics::{
-///     pixelcolor::Rgb565,
-///     prelude::*,
-///     text::Text,
-/// };
-///
-/// let style = MultiMonoTextStyleBuilder::new()
-///     .font(&FONT_6X9)
-///     .text_color(Rgb565::YELLOW)
-///     .background_color(Rgb565::BLUE)
-///     .build();
-///
-/// let text = Text::new("Hello Rust!", Point::new(0, 0), style);
-/// ```
-///
-/// ## Transparent background
-///
-/// If a property is omitted, it will remain at its default value in the resulting
-/// `MultiMonoTextStyle` returned by `.build()`. This example draws white text with no background at
-/// all.
-///
-/// ```rust
-/// use multi_mono_font::{ascii::FONT_6X9, MultiMonoTextStyle, MultiMonoTextStyleBuilder};
-/// use embedded_graphics::{
-///     pixelcolor::Rgb565,
-///     prelude::*,
-///     text::Text,
-/// };
-///
-/// let style = MultiMonoTextStyleBuilder::new()
-///     .font(&FONT_6X9)
-///     .text_color(Rgb565::WHITE)
-///     .build();
-///
-/// let text = Text::new("Hello Rust!", Point::new(0, 0), style);
-/// ```
-///
-/// ## Modifying an existing style
-///
-/// The builder can also be used to modify an existing style.
-///
-/// ```
-/// use multi_mono_font::{ascii::FONT_6X9, MultiMonoTextStyle, MultiMonoTextStyleBuilder};
-/// use embedded_graphics::{
-///     pixelcolor::Rgb565,
-///     prelude::*,
-///     text::Text,
-/// };
-///
-/// let style = MultiMonoTextStyle::new(&FONT_6X9, Rgb565::YELLOW);
-///
-/// let style_larger = MultiMonoTextStyleBuilder::from(&style)
-///     .font(&FONT_10X20)
-///     .build();
-/// ```
-///
-/// [`FONT_6X9`]: crate::::ascii::FONT_6X9
-/// [other fonts]: super
-/// [`Text`]: crate::text::Text
-#[derive(Copy, Clone, Debug)]
-#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
-pub struct MultiMonoTextStyleBuilder<'a, C> {
-    style: MultiMonoTextStyle<'a, C>,
-}
+    /// Returns `true` if `text` fits into a circle.
+    ///
+    /// The text is centered horizontally on `center` and positioned vertically by drawing it at
+    /// `center.y` with the given baseline. The text fits if all corners of its bounding box are
+    /// inside the circle, which is useful for round displays.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, text::Baseline};
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let center = Point::new(32, 32);
+    ///
+    /// assert!(style.fits_in_circle("Hello", center, 16, Baseline::Middle));
+    /// assert!(!style.fits_in_circle("Hello", center, 15, Baseline::Middle));
+    /// ```
+    pub fn fits_in_circle(
+        &self,
+        text: &str,
+        center: Point,
+        radius: u32,
+        baseline: Baseline,
+    ) -> bool {
+        let width = self
+            .measure_string(text, Point::zero(), baseline)
+            .bounding_box
+            .size
+            .width;
+        let position = Point::new(center.x - (width / 2) as i32, center.y);
+        let bounding_box = self.measure_string(text, position, baseline).bounding_box;
 
-impl<'a, C> MultiMonoTextStyleBuilder<'a, C>
-where
-    C: PixelColor,
-{
-    /// Creates a new text style builder.
-    pub const fn new(text_color: C) -> Self {
-        Self {
-            style: MultiMonoTextStyle {
-                fonts: &[&super::NULL_FONT],
-                background_color: None,
-                text_color,
-                line_height: 0,
-            },
-        }
+        let bottom_right = match bounding_box.bottom_right() {
+            Some(bottom_right) => bottom_right,
+            None => return true,
+        };
+        let top_left = bounding_box.top_left;
+
+        let dx = (top_left.x - center.x)
+            .unsigned_abs()
+            .max((bottom_right.x - center.x).unsigned_abs());
+        let dy = (top_left.y - center.y)
+            .unsigned_abs()
+            .max((bottom_right.y - center.y).unsigned_abs());
+
+        u64::from(dx).pow(2) + u64::from(dy).pow(2) <= u64::from(radius).pow(2)
     }
 
-    /// Sets the font.
-    pub const fn font<'b>(
-        self,
-        font_list: &'b [&'b MultiMonoFont<'b>],
-        line_height: MultiMonoLineHeight,
-    ) -> MultiMonoTextStyleBuilder<'b, C> {
-        let fonts = if font_list.len() == 0 {
-            &[&crate::NULL_FONT]
-        } else {
-            font_list
+    /// Draws at most `max_glyphs` characters of `text`, starting at the given cursor.
+    ///
+    /// Returns the cursor to resume drawing from in the next call. This makes it possible to
+    /// split drawing a long string into multiple calls, e.g. on cooperatively scheduled systems.
+    /// Drawing is finished when [`DrawCursor::is_done`] returns `true`.
+    ///
+    /// The chunks are laid out like a single call to [`draw_string`]: tab stops and leading tabs
+    /// continue from the preceding chunks, and the first glyph indent and the grid snapping are
+    /// only applied to the first chunk.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, DrawCursor, MultiMonoLineHeight, MultiMonoTextStyle,
+    ///     MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// let mut single = MockDisplay::new();
+    /// style
+    ///     .draw_string("Hello", Point::zero(), Baseline::Top, &mut single)
+    ///     .unwrap();
+    ///
+    /// let mut chunked = MockDisplay::new();
+    /// let mut cursor = DrawCursor::new(Point::zero());
+    /// cursor = style
+    ///     .draw_string_chunked("Hello", cursor, Baseline::Top, 3, &mut chunked)
+    ///     .unwrap();
+    /// assert!(!cursor.is_done("Hello"));
+    /// cursor = style
+    ///     .draw_string_chunked("Hello", cursor, Baseline::Top, 3, &mut chunked)
+    ///     .unwrap();
+    /// assert!(cursor.is_done("Hello"));
+    ///
+    /// chunked.assert_eq(&single);
+    ///
+    /// // the layout options are applied to the string as a whole
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .tab_stops(Some(16))
+    ///     .first_glyph_indent(5)
+    ///     .grid_snap(4)
+    ///     .build();
+    /// let text = "a\tbc";
+    ///
+    /// let mut single = MockDisplay::new();
+    /// let next = style
+    ///     .draw_string(text, Point::new(2, 2), Baseline::Top, &mut single)
+    ///     .unwrap();
+    /// assert_eq!(next, Point::new(28, 0));
+    ///
+    /// let mut chunked = MockDisplay::new();
+    /// let mut cursor = DrawCursor::new(Point::new(2, 2));
+    /// while !cursor.is_done(text) {
+    ///     cursor = style
+    ///         .draw_string_chunked(text, cursor, Baseline::Top, 1, &mut chunked)
+    ///         .unwrap();
+    /// }
+    /// assert_eq!(cursor.position, next);
+    ///
+    /// chunked.assert_eq(&single);
+    /// ```
+    ///
+    /// [`draw_string`]: TextRenderer::draw_string
+    pub fn draw_string_chunked<D>(
+        &self,
+        text: &str,
+        cursor: DrawCursor,
+        baseline: Baseline,
+        max_glyphs: usize,
+        target: &mut D,
+    ) -> Result<DrawCursor, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let start = cursor.offset.min(text.len());
+        let end = text[start..]
+            .char_indices()
+            .nth(max_glyphs)
+            .map_or(text.len(), |(index, _)| start + index);
+
+        let (position, line) = match start {
+            0 => (self.snap_to_grid(cursor.position), LineOffset::Start),
+            _ => (cursor.position, self.line_offset_after(&text[..start])),
         };
-        let line_height = get_line_height(line_height, fonts);
-        let style = MultiMonoTextStyle {
-            fonts,
-            background_color: self.style.background_color,
-            text_color: self.style.text_color,
+        let position = self.draw_line(&text[start..end], position, baseline, line, target)?;
+
+        Ok(DrawCursor {
+            offset: end,
+            position,
+        })
+    }
+
+    /// Draws a single line of text with the given line height instead of the line height of the
+    /// style.
+    ///
+    /// The line height sets the height of the background band and the alignment of
+    /// [`Baseline::Bottom`] and [`Baseline::Middle`].
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, primitives::Rectangle,
+    ///     text::Baseline,
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .background_color(BinaryColor::Off)
+    ///     .build();
+    ///
+    /// for line_height in [9, 12] {
+    ///     let mut display = MockDisplay::new();
+    ///     style
+    ///         .draw_string_with_line_height(
+    ///             "Hi",
+    ///             Point::zero(),
+    ///             Baseline::Top,
+    ///             line_height,
+    ///             &mut display,
+    ///         )
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(
+    ///         display.affected_area(),
+    ///         Rectangle::new(Point::zero(), Size::new(12, u32::from(line_height)))
+    ///     );
+    /// }
+    /// ```
+    pub fn draw_string_with_line_height<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        line_height: ChSzTy,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        Self {
             line_height,
+            ..*self
+        }
+        .draw_string(text, position, baseline, target)
+    }
+
+    /// Draws `text` into exactly `cols` character cells.
+    ///
+    /// Characters which don't fit into the first `cols` cells are truncated. Cells are counted as
+    /// laid out, i.e. combining marks, zero width spaces and ignored characters occupy no cell and
+    /// an expanded tab occupies one cell per space. If `text` is shorter, the remaining cells are
+    /// filled with the background color if one is set, using the cell width of the first font. Use
+    /// [`min_advance`] to get cells of the same width for all fonts.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .background_color(BinaryColor::Off)
+    ///     .build();
+    ///
+    /// let mut truncated = MockDisplay::new();
+    /// let next = style
+    ///     .draw_line_columns("abcdef", 4, Point::zero(), Baseline::Top, &mut truncated)
+    ///     .unwrap();
+    /// assert_eq!(next, Point::new(24, 0));
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// style
+    ///     .draw_string("abcd", Point::zero(), Baseline::Top, &mut expected)
+    ///     .unwrap();
+    /// truncated.assert_eq(&expected);
+    ///
+    /// let mut padded = MockDisplay::new();
+    /// let next = style
+    ///     .draw_line_columns("ab", 4, Point::zero(), Baseline::Top, &mut padded)
+    ///     .unwrap();
+    /// assert_eq!(next, Point::new(24, 0));
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// style
+    ///     .draw_string("ab  ", Point::zero(), Baseline::Top, &mut expected)
+    ///     .unwrap();
+    /// padded.assert_eq(&expected);
+    ///
+    /// // the expanded tab occupies two cells, the zero width space and the combining mark none
+    /// let style = MultiMonoTextStyleBuilder::from(&style)
+    ///     .tab_expansion(Some(2))
+    ///     .combining_chars(&['\u{301}'], 0)
+    ///     .build();
+    ///
+    /// let mut cells = MockDisplay::new();
+    /// cells.set_allow_overdraw(true);
+    /// let text = "\ta\u{200B}\u{301}bc";
+    /// let next = style
+    ///     .draw_line_columns(text, 4, Point::zero(), Baseline::Top, &mut cells)
+    ///     .unwrap();
+    /// assert_eq!(next, Point::new(24, 0));
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// expected.set_allow_overdraw(true);
+    /// style
+    ///     .draw_string("  a\u{301}b", Point::zero(), Baseline::Top, &mut expected)
+    ///     .unwrap();
+    /// cells.assert_eq(&expected);
+    /// ```
+    ///
+    /// [`min_advance`]: MultiMonoTextStyleBuilder::min_advance
+    pub fn draw_line_columns<D>(
+        &self,
+        text: &str,
+        cols: usize,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let cells = |text: &str| {
+            self.layout_advances(text, LineOffset::Start)
+                .filter(|(_, _, advance)| *advance != 0)
+                .count()
         };
 
-        MultiMonoTextStyleBuilder { style }
+        let end = text
+            .char_indices()
+            .map(|(index, c)| index + c.len_utf8())
+            .take_while(|end| cells(&text[..*end]) <= cols)
+            .last()
+            .unwrap_or_default();
+        let text = &text[..end];
+
+        let position = self.draw_string(text, position, baseline, target)?;
+
+        let padding = cols.saturating_sub(cells(text)) as u32;
+        let width = padding * self.cell_advance(' ', self.fonts[0]);
+
+        self.draw_whitespace(width, position, baseline, target)
     }
 
-    /// Resets the background color to transparent.
-    pub const fn reset_background_color(mut self) -> Self {
-        self.style.background_color = None;
+    /// Returns an iterator over the lines of `text` wrapped to `width`.
+    ///
+    /// Lines are broken at explicit line breaks and at spaces, spaces at the wrap position are
+    /// removed. Words which are wider than `width` are split between characters. This is the
+    /// line breaking which is used by [`Paragraph`].
+    ///
+    /// Zero width spaces (U+200B) are additional break opportunities. They are removed at the
+    /// wrap position and aren't drawn or measured anywhere else. No-break spaces (U+00A0) advance
+    /// like a space, but are never break opportunities.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// assert!(style
+    ///     .wrap("the quick brown fox jumps", 60)
+    ///     .eq(["the quick", "brown fox", "jumps"]));
+    /// assert!(style.wrap("abcdefgh", 30).eq(["abcde", "fgh"]));
+    ///
+    /// // words which exactly fill the width
+    /// assert!(style.wrap("aaa bbb ccc", 18).eq(["aaa", "bbb", "ccc"]));
+    /// assert!(style.wrap("abc def ", 18).eq(["abc", "def"]));
+    ///
+    /// let url = "www.example.com/\u{200B}some/\u{200B}path";
+    /// assert!(style
+    ///     .wrap(url, 102)
+    ///     .eq(["www.example.com/", "some/\u{200B}path"]));
+    ///
+    /// let metrics = style.measure_string("some/\u{200B}path", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size.width, 9 * 6);
+    ///
+    /// // the quantity and the unit are kept together
+    /// assert!(style
+    ///     .wrap("10\u{A0}kg fits", 36)
+    ///     .eq(["10\u{A0}kg", "fits"]));
+    ///
+    /// let metrics = style.measure_string("10\u{A0}kg", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size.width, 5 * 6);
+    /// ```
+    pub fn wrap<'t>(&'t self, text: &'t str, width: u32) -> impl Iterator<Item = &'t str> + 't {
+        WrapIter::new(text, self, width)
+    }
 
-        self
+    /// Returns an iterator over the lines of `text` wrapped to `width` with additional break
+    /// opportunities.
+    ///
+    /// This works like [`wrap`], but lines can also be broken before the characters at the byte
+    /// offsets in `breaks`. This makes it possible to wrap languages without spaces between words,
+    /// like Thai or Japanese, at word boundaries which were found by a dictionary.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let text = "thequickbrownfox";
+    ///
+    /// assert!(style.wrap(text, 60).eq(["thequickbr", "ownfox"]));
+    /// assert!(style
+    ///     .wrap_with_breaks(text, 60, &[3, 8, 13])
+    ///     .eq(["thequick", "brownfox"]));
+    /// ```
+    ///
+    /// [`wrap`]: MultiMonoTextStyle::wrap
+    pub fn wrap_with_breaks<'t>(
+        &'t self,
+        text: &'t str,
+        width: u32,
+        breaks: &'t [usize],
+    ) -> impl Iterator<Item = &'t str> + 't {
+        WrapIter::new(text, self, width).with_breaks(breaks)
     }
 
-    /// Sets the text color.
-    pub const fn text_color(mut self, text_color: C) -> Self {
-        self.style.text_color = text_color;
+    /// Returns the number of lines `text` is wrapped into at `width`.
+    ///
+    /// Explicit line breaks and lines broken by [`wrap`] are counted.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// assert_eq!(style.wrapped_line_count("the quick brown\nfox", 60), 3);
+    /// assert_eq!(style.wrapped_line_count("", 60), 1);
+    /// ```
+    ///
+    /// [`wrap`]: MultiMonoTextStyle::wrap
+    pub fn wrapped_line_count(&self, text: &str, width: u32) -> usize {
+        self.wrap(text, width).count()
+    }
 
-        self
+    /// Measures the first line of text.
+    ///
+    /// Measuring stops at the first `\n`, the remaining lines are ignored. [`measure_string`]
+    /// measures a `\n` like any other character, because [`draw_string`] draws it with the
+    /// replacement glyph.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// assert_eq!(
+    ///     style.measure_line("ab\ncd", Point::zero(), Baseline::Top),
+    ///     style.measure_string("ab", Point::zero(), Baseline::Top),
+    /// );
+    ///
+    /// // measure_string matches the drawn string
+    /// let metrics = style.measure_string("ab\ncd", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.next_position, Point::new(30, 0));
+    /// ```
+    ///
+    /// [`measure_string`]: TextRenderer::measure_string
+    /// [`draw_string`]: TextRenderer::draw_string
+    pub fn measure_line(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let text = text.split('\n').next().unwrap_or_default();
+
+        self.measure_string(text, position, baseline)
     }
 
-    /// Sets the line height.
-    pub const fn line_height(mut self, line_height: MultiMonoLineHeight) -> Self {
-        self.style.line_height = get_line_height(line_height, self.style.fonts);
+    /// Measures a single line of text which continues a line at the pen position `start_x`.
+    ///
+    /// Unlike [`measure_string`], tab stops are relative to the start of the line and not to the
+    /// start of `text`. The returned metrics are positioned as if `text` was drawn at
+    /// `(start_x, 0)`. The first glyph indent is only applied if `start_x` is `0`, otherwise it
+    /// is expected to be included in `start_x`.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, text::Baseline};
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_4X6], MultiMonoLineHeight::Max)
+    ///     .tab_stops(Some(16))
+    ///     .build();
+    ///
+    /// let metrics = style.measure_string_from("\tb", 0, Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size.width, 20);
+    ///
+    /// let metrics = style.measure_string_from("\tb", 4, Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size.width, 16);
+    /// assert_eq!(metrics.next_position, Point::new(20, 0));
+    /// ```
+    ///
+    /// [`measure_string`]: TextRenderer::measure_string
+    pub fn measure_string_from(&self, text: &str, start_x: u32, baseline: Baseline) -> TextMetrics {
+        let line = match start_x {
+            0 => LineOffset::Start,
+            x => LineOffset::Continue { x, leading: false },
+        };
 
-        self
+        self.measure_in_line(text, Point::new(start_x as i32, 0), baseline, line)
     }
 
-    /// Sets the background color.
-    pub const fn background_color(mut self, background_color: C) -> Self {
-        self.style.background_color = Some(background_color);
+    /// Returns the letter spacing which is needed to fit `text` into `width`.
+    ///
+    /// The returned value replaces the [`letter_spacing`] of this style. It is `0` if the text
+    /// already fits and is clamped to `min_spacing` if the text can't be tightened enough.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let builder = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max);
+    /// let style = builder.build();
+    ///
+    /// let width = |style: &MultiMonoTextStyle<BinaryColor>| {
+    ///     style.measure_string("abcdef", Point::zero(), Baseline::Top).bounding_box.size.width
+    /// };
+    /// assert_eq!(width(&style), 36);
+    ///
+    /// let letter_spacing = style.fit_by_tracking("abcdef", 32, -3);
+    /// assert_eq!(letter_spacing, -1);
+    /// assert!(width(&builder.letter_spacing(letter_spacing).build()) <= 32);
+    ///
+    /// assert_eq!(style.fit_by_tracking("abcdef", 40, -3), 0);
+    /// assert_eq!(style.fit_by_tracking("abcdef", 10, -3), -3);
+    /// ```
+    ///
+    /// [`letter_spacing`]: MultiMonoTextStyle::letter_spacing
+    pub fn fit_by_tracking(&self, text: &str, width: u32, min_spacing: i8) -> i8 {
+        let style = Self {
+            letter_spacing: 0,
+            ..*self
+        };
+
+        let text_width = style
+            .measure_string(text, Point::zero(), Baseline::Top)
+            .bounding_box
+            .size
+            .width;
+        let gaps = style.layout_chars(text).count().saturating_sub(1);
+        if text_width <= width || gaps == 0 {
+            return 0;
+        }
+
+        let overflow = text_width - width;
+        let letter_spacing = -(overflow.div_ceil(gaps as u32) as i32);
+
+        letter_spacing.clamp(min_spacing as i32, 0) as i8
+    }
+
+    /// Returns the fonts which are used for the first and the last character of `text`.
+    ///
+    /// Both fonts are `None` for empty strings. This can be used to decide on the spacing between
+    /// text runs which are drawn separately.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_10X20, FONT_6X9},
+    ///     mapping::StrGlyphMapping,
+    ///     CharSize, MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyle,
+    /// };
+    ///
+    /// const CJK_FONT: MultiMonoFont = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("字体", 0),
+    ///     ..FONT_10X20
+    /// };
+    ///
+    /// let style = MultiMonoTextStyle::new(
+    ///     &[&FONT_6X9, &CJK_FONT],
+    ///     MultiMonoLineHeight::Max,
+    ///     BinaryColor::On,
+    /// );
+    ///
+    /// let (first, last) = style.boundary_fonts("ab字");
+    /// assert_eq!(first.map(|font| font.character_size), Some(CharSize::new(6, 9)));
+    /// assert_eq!(last.map(|font| font.character_size), Some(CharSize::new(10, 20)));
+    ///
+    /// assert_eq!(style.boundary_fonts(""), (None, None));
+    /// ```
+    pub fn boundary_fonts(
+        &self,
+        text: &str,
+    ) -> (Option<&'a MultiMonoFont<'a>>, Option<&'a MultiMonoFont<'a>>) {
+        let mut fonts = self.layout_chars(text).map(|(_, font)| font);
+        let first = fonts.next();
+        let last = fonts.last().or(first);
+
+        (first, last)
+    }
+
+    /// Draws glyphs at the given positions.
+    ///
+    /// Each character of `chars` is drawn at the corresponding position in `positions`, without
+    /// laying out the characters as a line. This makes it possible to use the glyph rendering of
+    /// this style with custom shaping, where the caller has already computed the visual order
+    /// and the positions of the glyphs. Surplus characters or positions are ignored.
+    ///
+    /// The options of the style which lay out a string, like the first glyph indent, the grid
+    /// snapping, ligatures, ignored characters, replacement strings and the character order, aren't
+    /// applied and the clip warning isn't checked.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let chars = ['c', 'a', 'b'];
+    /// let positions = [Point::new(20, 0), Point::new(1, 10), Point::new(7, 30)];
+    ///
+    /// let mut display = MockDisplay::new();
+    /// style
+    ///     .draw_visual_order(&chars, &positions, Baseline::Top, &mut display)
+    ///     .unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// for (c, position) in ["c", "a", "b"].into_iter().zip(positions) {
+    ///     Text::with_baseline(c, position, style, Baseline::Top)
+    ///         .draw(&mut expected)
+    ///         .unwrap();
+    /// }
+    ///
+    /// display.assert_eq(&expected);
+    ///
+    /// // the glyphs are drawn at the exact positions, without indent and grid snapping
+    /// let indented = MultiMonoTextStyleBuilder::from(&style)
+    ///     .first_glyph_indent(5)
+    ///     .grid_snap(4)
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// indented
+    ///     .draw_visual_order(&chars, &positions, Baseline::Top, &mut display)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub fn draw_visual_order<D>(
+        &self,
+        chars: &[char],
+        positions: &[Point],
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let glyph_style = Self {
+            reverse_chars: false,
+            bidi_auto: false,
+            ligatures: &[],
+            ignore_chars: &[],
+            replacement_str: None,
+            first_glyph_indent: 0,
+            ..*self
+        };
+
+        let mut buffer = [0; 4];
+        for (c, position) in chars.iter().zip(positions) {
+            let text = c.encode_utf8(&mut buffer);
+            glyph_style.draw_line(text, *position, baseline, LineOffset::Start, target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draws a single line of text with the colors of a [`ColorMap`].
+    ///
+    /// The color map replaces the text and background colors of the style, which makes it
+    /// possible to choose the colors per draw target type. The background is drawn if the color
+    /// map has a color for `Off` pixels, independent of the background color of the style.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, BinaryColorMap, MultiMonoLineHeight, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(Rgb565::WHITE)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .background_color(Rgb565::BLACK)
+    ///     .build();
+    /// let color_map = BinaryColorMap {
+    ///     on: Some(Rgb565::RED),
+    ///     off: None,
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    /// style
+    ///     .draw_string_mapped("Hi", Point::zero(), Baseline::Top, &color_map, &mut display)
+    ///     .unwrap();
+    ///
+    /// let red = MultiMonoTextStyleBuilder::new(Rgb565::RED)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .build();
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("Hi", Point::zero(), red, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    ///
+    /// // the background is drawn for a style with a transparent background
+    /// let color_map = BinaryColorMap {
+    ///     on: Some(Rgb565::RED),
+    ///     off: Some(Rgb565::BLUE),
+    /// };
+    /// let mut display = MockDisplay::new();
+    /// red.draw_string_mapped("Hi", Point::zero(), Baseline::Top, &color_map, &mut display)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::BLUE));
+    /// assert_eq!(display.affected_area().size, Size::new(12, 9));
+    /// ```
+    pub fn draw_string_mapped<D, M>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        color_map: &M,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        M: ColorMap<C>,
+    {
+        // background pixels are drawn if the color map has a color for them
+        self.draw_string_binary(
+            text,
+            position,
+            baseline,
+            LineOffset::Start,
+            ColorMapDrawTarget::new(target, color_map),
+        )
+    }
+
+    /// Returns the metrics of the glyph which is drawn for the given character.
+    ///
+    /// The advance of a tab is calculated for a tab at the start of a line. Ignored characters
+    /// have empty metrics.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_10X20, FONT_6X9},
+    ///     mapping::StrGlyphMapping,
+    ///     GlyphMetrics, MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyle,
+    /// };
+    ///
+    /// const CJK: MultiMonoFont = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("\u{5B57}", 0),
+    ///     ..FONT_10X20
+    /// };
+    ///
+    /// let fonts = [&FONT_6X9, &CJK];
+    /// let style = MultiMonoTextStyle::new(&fonts, MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// assert_eq!(
+    ///     style.glyph_metrics('a'),
+    ///     GlyphMetrics {
+    ///         advance: 6,
+    ///         size: Size::new(6, 9),
+    ///         baseline: 6,
+    ///     }
+    /// );
+    /// assert_eq!(
+    ///     style.glyph_metrics('\u{5B57}'),
+    ///     GlyphMetrics {
+    ///         advance: 10,
+    ///         size: Size::new(10, 20),
+    ///         baseline: 15,
+    ///     }
+    /// );
+    /// ```
+    pub fn glyph_metrics(&self, c: char) -> GlyphMetrics {
+        let mut buffer = [0; 4];
+        let text = c.encode_utf8(&mut buffer);
+
+        let glyph = self.layout_advances(text, LineOffset::Start).next();
+
+        match glyph {
+            Some((c, font, advance)) => GlyphMetrics {
+                advance,
+                size: Size::new(
+                    font.character_size.width as u32,
+                    font.character_size.height as u32,
+                ),
+                baseline: font.glyph_baseline(c) as u32,
+            },
+            None => GlyphMetrics {
+                advance: 0,
+                size: Size::zero(),
+                baseline: 0,
+            },
+        }
+    }
+
+    /// Returns the height of a multiline text.
+    ///
+    /// The height is the number of lines, separated by `\n`, times the line height. The style has
+    /// no text decorations, which could extend below the last line, so nothing else is added.
+    ///
+    /// A trailing `\n` starts an empty last line, which is included in the height. Use
+    /// [`total_height_trimmed`] to ignore it.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// assert_eq!(style.total_height("ab"), 9);
+    /// assert_eq!(style.total_height("ab\ncd"), 2 * 9);
+    /// assert_eq!(style.total_height(""), 9);
+    /// assert_eq!(style.total_height("a\n"), 2 * 9);
+    /// ```
+    ///
+    /// [`total_height_trimmed`]: MultiMonoTextStyle::total_height_trimmed
+    pub fn total_height(&self, text: &str) -> u32 {
+        text.split('\n').count() as u32 * TextRenderer::line_height(self)
+    }
+
+    /// Returns the height of a multiline text, ignoring the empty line after a trailing `\n`.
+    ///
+    /// This matches the behavior of most text editors, which don't count a final line break as
+    /// the start of another line. Only a single trailing line break is ignored.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// assert_eq!(style.total_height_trimmed("a"), 9);
+    /// assert_eq!(style.total_height_trimmed("a\n"), 9);
+    /// assert_eq!(style.total_height_trimmed("a\r\n"), 9);
+    /// assert_eq!(style.total_height_trimmed("a\n\n"), 2 * 9);
+    /// assert_eq!(style.total_height_trimmed(""), 9);
+    /// ```
+    pub fn total_height_trimmed(&self, text: &str) -> u32 {
+        let text = text
+            .strip_suffix('\n')
+            .map_or(text, |text| text.strip_suffix('\r').unwrap_or(text));
+
+        self.total_height(text)
+    }
+
+    /// Returns how far the glyphs of `text` extend below the line position.
+    ///
+    /// For [`Baseline::Alphabetic`] this is the largest descent of the glyphs below the baseline.
+    /// The font and the baseline of each glyph are taken into account.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::BinaryColor, text::Baseline};
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_10X20, FONT_6X9},
+    ///     mapping::StrGlyphMapping,
+    ///     MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyle,
+    /// };
+    ///
+    /// const CJK: MultiMonoFont = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("\u{5B57}", 0),
+    ///     ..FONT_10X20
+    /// };
+    ///
+    /// let fonts = [&FONT_6X9, &CJK];
+    /// let style = MultiMonoTextStyle::new(&fonts, MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// assert_eq!(style.descent_for("ab", Baseline::Alphabetic), 2);
+    /// assert_eq!(style.descent_for("a\u{5B57}", Baseline::Alphabetic), 4);
+    /// assert_eq!(style.descent_for("", Baseline::Alphabetic), 0);
+    /// ```
+    pub fn descent_for(&self, text: &str, baseline: Baseline) -> u32 {
+        self.layout_chars(text)
+            .map(|(c, font)| {
+                let bottom = font.character_size.height.saturating_sub(1) as i32;
+                (bottom - self.baseline_offset(baseline, font, c)).max(0) as u32
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the pen x position after each character of `text`.
+    ///
+    /// The offsets are relative to the start of the string and include the character spacing.
+    /// They can be used for caret placement or to map a click position to a character. Like the
+    /// advance of drawn text, the offsets are divided by the [`shrink`] factor.
+    ///
+    /// The Unicode space variants no-break space, en space, em space and thin space advance by
+    /// one, one, two and a half times the width of a normal space, unless the font contains a
+    /// glyph for them.
+    ///
+    /// ```
+    /// use core::num::NonZeroU8;
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyle,
+    ///     MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const FONT: MultiMonoFont = MultiMonoFont {
+    ///     character_spacing: 2,
+    ///     ..FONT_6X9
+    /// };
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// assert!(style.char_offsets("AB").eq([8, 16]));
+    ///
+    /// // an em space is twice as wide as a normal space
+    /// assert!(style.char_offsets("A B").eq([8, 16, 24]));
+    /// assert!(style.char_offsets("A\u{2003}B").eq([8, 24, 32]));
+    ///
+    /// // downscaled text
+    /// let small = MultiMonoTextStyleBuilder::from(&style)
+    ///     .shrink(NonZeroU8::new(2).unwrap())
+    ///     .build();
+    /// assert!(small.char_offsets("AB").eq([4, 8]));
+    /// ```
+    ///
+    /// [`shrink`]: MultiMonoTextStyle::shrink
+    pub fn char_offsets<'t>(&'t self, text: &'t str) -> impl Iterator<Item = u32> + 't {
+        self.layout_advances(text, LineOffset::Start)
+            .scan(self.first_glyph_indent, |x, (_, _, advance)| {
+                *x += advance;
+                Some(*x)
+            })
+            .map(|x| {
+                let offset = self.shrink_advance(Point::zero(), Point::new(x as i32, 0));
+                offset.x as u32
+            })
+    }
+
+    /// Draws `text` with an outline around each glyph cell and a line at the baseline.
+    ///
+    /// The cell outlines span the advance of each glyph, including the character spacing. This is
+    /// useful to verify the spacing and baseline of fonts while authoring them.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::Rgb565, prelude::*, text::Baseline,
+    /// };
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_4X6], MultiMonoLineHeight::Max, Rgb565::WHITE);
+    ///
+    /// let mut display = MockDisplay::new();
+    /// display.set_allow_overdraw(true);
+    /// style
+    ///     .draw_string_debug("ab", Point::new(0, 4), Baseline::Alphabetic, &mut display, Rgb565::RED)
+    ///     .unwrap();
+    ///
+    /// // right edge of the first cell and left edge of the second cell
+    /// assert_eq!(display.get_pixel(Point::new(3, 2)), Some(Rgb565::RED));
+    /// assert_eq!(display.get_pixel(Point::new(4, 2)), Some(Rgb565::RED));
+    /// // baseline
+    /// assert_eq!(display.get_pixel(Point::new(1, 4)), Some(Rgb565::RED));
+    /// ```
+    pub fn draw_string_debug<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+        grid_color: C,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let next_position = self.draw_string(text, position, baseline, target)?;
+
+        let grid_style = PrimitiveStyle::with_stroke(grid_color, 1);
+        let mut x = position.x + self.first_glyph_indent as i32;
+        for (c, font, advance) in self.layout_advances(text, LineOffset::Start) {
+            let top = position.y - self.baseline_offset(baseline, font, c);
+            Rectangle::new(
+                Point::new(x, top),
+                Size::new(advance, font.character_size.height as u32),
+            )
+            .into_styled(grid_style)
+            .draw(target)?;
+            x += advance as i32;
+        }
+
+        if x > position.x {
+            Line::new(position, Point::new(x - 1, position.y))
+                .into_styled(grid_style)
+                .draw(target)?;
+        }
+
+        Ok(next_position)
+    }
+
+    /// Reports the coverage of all pixels covered by the glyphs of `text` to `f`.
+    ///
+    /// The coverage is `255` for pixels which are drawn in the text color and `0` for background
+    /// pixels inside the glyph images. This makes it possible to composite text without drawing
+    /// it to a draw target first. The glyphs are laid out like a single line of text drawn by
+    /// [`draw_string`], including the options which change the glyph shapes, e.g. hollow,
+    /// flipped, clipped and shrunk glyphs.
+    ///
+    /// Returns the position after the last character.
+    ///
+    /// ```
+    /// use core::num::NonZeroU8;
+    ///
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let next = style.coverage("A", Point::zero(), Baseline::Top, |point, coverage| {
+    ///     if coverage == 255 {
+    ///         display.set_pixel(point, Some(BinaryColor::On));
+    ///     }
+    /// });
+    /// assert_eq!(next, Point::new(6, 0));
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("A", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    ///
+    /// // the glyph options are applied like when drawing
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .hollow(true)
+    ///     .clip_rows(Some((2, 6)))
+    ///     .shrink(NonZeroU8::new(2).unwrap())
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let next = style.coverage("AB", Point::zero(), Baseline::Top, |point, coverage| {
+    ///     if coverage == 255 {
+    ///         display.set_pixel(point, Some(BinaryColor::On));
+    ///     }
+    /// });
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// let expected_next = Text::with_baseline("AB", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(next, expected_next);
+    /// display.assert_eq(&expected);
+    /// ```
+    ///
+    /// [`draw_string`]: TextRenderer::draw_string
+    pub fn coverage<F>(&self, text: &str, position: Point, baseline: Baseline, f: F) -> Point
+    where
+        F: FnMut(Point, u8),
+    {
+        let position = self.snap_to_grid(position);
+        let mut target = CoverageDrawTarget::new(f);
+
+        let factor = self.shrink.get();
+        let next_position = if factor == 1 {
+            self.draw_string_binary(text, position, baseline, LineOffset::Start, target)
+        } else {
+            self.draw_string_binary(
+                text,
+                position,
+                baseline,
+                LineOffset::Start,
+                ShrinkDrawTarget::new(&mut target, position, factor),
+            )
+            .map(|next_position| self.shrink_advance(position, next_position))
+        };
+
+        match next_position {
+            Ok(next_position) => next_position,
+            Err(never) => match never {},
+        }
+    }
+
+    fn get_font_info(&self, c: char) -> &'a MultiMonoFont<'a> {
+        self.resolve_font(c)
+            .or(self.replacement_font)
+            .unwrap_or(self.fonts[0])
+    }
+
+    /// Returns the font which contains a glyph for the given character, including the fonts of
+    /// the fallback style.
+    fn resolve_font(&self, c: char) -> Option<&'a MultiMonoFont<'a>> {
+        self.find_font(c)
+            .or_else(|| self.fallback.and_then(|fallback| fallback.resolve_font(c)))
+    }
+
+    /// Returns the font which contains a glyph for the given character.
+    ///
+    /// The font is selected by the tie-break policy if multiple fonts contain the glyph.
+    fn find_font(&self, c: char) -> Option<&'a MultiMonoFont<'a>> {
+        let cell_area = |font: &MultiMonoFont<'a>| {
+            font.character_size.width as u32 * font.character_size.height as u32
+        };
+        let mut fonts = self.fonts.iter().copied().filter(|font| font.contains(c));
+
+        match self.font_tie_break {
+            FontTieBreak::FirstInSlice => fonts.next(),
+            FontTieBreak::LargestCell => fonts.fold(None, |largest, font| match largest {
+                Some(largest) if cell_area(largest) >= cell_area(font) => Some(largest),
+                _ => Some(font),
+            }),
+            FontTieBreak::SmallestCell => fonts.min_by_key(|font| cell_area(font)),
+        }
+    }
+
+    /// Returns the index of a font in the font list of the style.
+    fn font_index(&self, font: &MultiMonoFont<'a>) -> Option<usize> {
+        self.fonts.iter().position(|f| core::ptr::eq(*f, font))
+    }
+
+    /// Returns an iterator over the characters of `text` and the fonts used to lay them out.
+    fn layout_chars<'t>(
+        &'t self,
+        text: &'t str,
+    ) -> impl Iterator<Item = (char, &'a MultiMonoFont<'a>)> + 't {
+        let mut previous = None;
+
+        let reversed = self
+            .reverse_chars
+            .then(|| text.char_indices().rev())
+            .into_iter()
+            .flatten();
+        let forward = (!self.reverse_chars)
+            .then(|| visual_order(text, self.bidi_auto, self.ligatures))
+            .into_iter()
+            .flatten();
+
+        forward
+            .chain(reversed)
+            .filter(move |(_, c)| *c != ZERO_WIDTH_SPACE && !self.ignore_chars.contains(c))
+            .flat_map(move |(index, c)| {
+                let (c, count) = match (c, self.tab_expansion) {
+                    ('\t', Some(spaces)) => (' ', spaces as usize),
+                    _ => (c, 1),
+                };
+                let c = match self.replacement_fn {
+                    Some(replacement_fn) if self.resolve_font(c).is_none() => replacement_fn(c),
+                    _ => c,
+                };
+                let (count, replacement) = match self.replacement_str {
+                    Some(replacement_str) if self.resolve_font(c).is_none() => (0, replacement_str),
+                    _ => (count, ""),
+                };
+
+                let font = if c == ' ' && self.space_follows_context {
+                    previous
+                        .or_else(|| {
+                            text[index..]
+                                .chars()
+                                .find(|c| *c != ' ' && !self.ignore_chars.contains(c))
+                                .map(|c| self.get_font_info(c))
+                        })
+                        .unwrap_or_else(|| self.get_font_info(c))
+                } else {
+                    self.get_font_info(c)
+                };
+                previous = Some(font);
+
+                core::iter::repeat((c, font))
+                    .take(count)
+                    .chain(replacement.chars().map(move |c| (c, self.get_font_info(c))))
+            })
+    }
+
+    /// Returns the characters of `text` in drawing order together with their font and advance.
+    ///
+    /// The advances are calculated for a string at the given position in its line.
+    fn layout_advances<'t>(
+        &'t self,
+        text: &'t str,
+        line: LineOffset,
+    ) -> impl Iterator<Item = (char, &'a MultiMonoFont<'a>, u32)> + 't {
+        let (mut x, mut leading) = match line {
+            LineOffset::Start => (self.first_glyph_indent, true),
+            LineOffset::Continue { x, leading } => (x, leading),
+        };
+
+        self.layout_chars(text).map(move |(c, font)| {
+            leading &= c == '\t';
+            let advance = match self.indent_tab_width {
+                Some(indent_tab_width) if leading => indent_tab_width,
+                _ => self.char_advance(c, font, x),
+            };
+            x += advance;
+
+            // tabs at the start of the next line are leading tabs again
+            if c == '\n' {
+                x = 0;
+                leading = true;
+            }
+
+            (c, font, advance)
+        })
+    }
+
+    /// Returns the position in the line after `text`, which starts a line.
+    fn line_offset_after(&self, text: &str) -> LineOffset {
+        let mut x = self.first_glyph_indent;
+        let mut leading = true;
+        for (c, _, advance) in self.layout_advances(text, LineOffset::Start) {
+            x += advance;
+            leading &= c == '\t';
+
+            if c == '\n' {
+                x = 0;
+                leading = true;
+            }
+        }
+
+        LineOffset::Continue { x, leading }
+    }
+
+    /// Draws a single line of text without snapping the position to the grid.
+    fn draw_line<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        line: LineOffset,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let factor = self.shrink.get();
+        if factor == 1 {
+            return self.draw_string_binary(
+                text,
+                position,
+                baseline,
+                line,
+                MultiMonoFontDrawTarget::new(target, self.text_color, self.background_color)
+                    .with_fg_pattern(self.fg_pattern),
+            );
+        }
+
+        let mut target = ShrinkDrawTarget::new(target, position, factor);
+        let next_position = self.draw_string_binary(
+            text,
+            position,
+            baseline,
+            line,
+            MultiMonoFontDrawTarget::new(&mut target, self.text_color, self.background_color)
+                .with_fg_pattern(self.fg_pattern),
+        )?;
+
+        Ok(self.shrink_advance(position, next_position))
+    }
+
+    fn draw_string_binary<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        line: LineOffset,
+        mut target: D,
+    ) -> Result<Point, D::Error>
+    where
+        D: GlyphDrawTarget<'a, C>,
+    {
+        let mut next_pos = position;
+        let (band_offset, band_height) = self.line_band(baseline);
+        let band_top = position.y - band_offset;
+        let band_bottom = band_top + band_height as i32;
+        let mut glyph_cache = self.glyph_cache.then(GlyphCache::new);
+
+        let indent = match line {
+            LineOffset::Start => self.first_glyph_indent,
+            LineOffset::Continue { .. } => 0,
+        };
+        target.set_colors(self.text_color, self.background_color, self.fg_pattern);
+        if target.fills_background() && indent > 0 {
+            target.fill_solid(
+                &Rectangle::new(
+                    Point::new(next_pos.x, band_top),
+                    Size::new(indent, band_height),
+                ),
+                BinaryColor::Off,
+            )?;
+        }
+        next_pos.x += indent as i32;
+
+        // position and width of the last glyph cell, which combining characters are drawn over
+        let mut previous_cell = (next_pos.x, 0);
+
+        for (c, font, advance) in self.layout_advances(text, line) {
+            let cell_pos = next_pos - Point::new(0, self.baseline_offset(baseline, font, c));
+            let cell_width = self.cell_width(c, font);
+            let height = font.character_size.height as u32;
+            let Some((text_color, background_color, fg_pattern)) = self.glyph_colors(font) else {
+                if !self.is_combining(c) {
+                    previous_cell = (next_pos.x, cell_width);
+                    next_pos.x += advance as i32;
+                }
+                continue;
+            };
+            target.set_colors(text_color, background_color, fg_pattern);
+
+            if self.is_combining(c) {
+                let (cell_x, previous_width) = previous_cell;
+                let offset = (previous_width as i32 - font.character_size.width as i32) / 2;
+                let mark_pos =
+                    Point::new(cell_x + offset, cell_pos.y + self.combining_y_offset as i32);
+                self.draw_mark(font, c, mark_pos, &mut target)?;
+
+                continue;
+            }
+            previous_cell = (next_pos.x, cell_width);
+
+            if self.is_blank(c, font) {
+                if target.fills_background() {
+                    target.fill_solid(
+                        &Rectangle::new(cell_pos, Size::new(advance, height)),
+                        BinaryColor::Off,
+                    )?;
+                }
+            } else {
+                let glyph_width = font.character_size.width as u32;
+                let glyph_offset = (cell_width - glyph_width) / 2;
+                let padding_right = advance.saturating_sub(glyph_offset + glyph_width);
+
+                if target.fills_background() && glyph_offset > 0 {
+                    target.fill_solid(
+                        &Rectangle::new(cell_pos, Size::new(glyph_offset, height)),
+                        BinaryColor::Off,
+                    )?;
+                }
+
+                let glyph_pos = cell_pos + Point::new(glyph_offset as i32, 0);
+                let flipped = self.flip_chars.contains(&c);
+                let area = match glyph_cache.as_mut() {
+                    Some(glyph_cache) => glyph_cache.glyph_rect(c, font),
+                    None => font.glyph_atlas_rect(c),
+                };
+                match self.clip_rows {
+                    Some((start, end)) => {
+                        let start = (start as u32).min(height);
+                        let end = (end as u32).clamp(start, height);
+
+                        if target.fills_background() && start > 0 {
+                            target.fill_solid(
+                                &Rectangle::new(glyph_pos, Size::new(glyph_width, start)),
+                                BinaryColor::Off,
+                            )?;
+                        }
+
+                        if end > start && !area.is_zero_sized() {
+                            let rows = Rectangle::new(
+                                area.top_left + Point::new(0, start as i32),
+                                Size::new(area.size.width, end - start),
+                            );
+                            self.draw_glyph(
+                                font,
+                                &area,
+                                rows,
+                                glyph_pos + Point::new(0, start as i32),
+                                flipped,
+                                &mut target,
+                            )?;
+                        }
+
+                        if target.fills_background() && end < height {
+                            target.fill_solid(
+                                &Rectangle::new(
+                                    glyph_pos + Point::new(0, end as i32),
+                                    Size::new(glyph_width, height - end),
+                                ),
+                                BinaryColor::Off,
+                            )?;
+                        }
+                    }
+                    None => self.draw_glyph(font, &area, area, glyph_pos, flipped, &mut target)?,
+                }
+
+                if target.fills_background() && padding_right > 0 {
+                    target.fill_solid(
+                        &Rectangle::new(
+                            glyph_pos + Point::new(glyph_width as i32, 0),
+                            Size::new(padding_right, height),
+                        ),
+                        BinaryColor::Off,
+                    )?;
+                }
+            }
+
+            // fill the parts of the line which are above and below the glyph cell
+            if target.fills_background() {
+                let above = cell_pos.y - band_top;
+                if above > 0 {
+                    target.fill_solid(
+                        &Rectangle::new(
+                            Point::new(next_pos.x, band_top),
+                            Size::new(advance, above as u32),
+                        ),
+                        BinaryColor::Off,
+                    )?;
+                }
+
+                let cell_bottom = cell_pos.y + height as i32;
+                let below = band_bottom - cell_bottom;
+                if below > 0 {
+                    target.fill_solid(
+                        &Rectangle::new(
+                            Point::new(next_pos.x, cell_bottom),
+                            Size::new(advance, below as u32),
+                        ),
+                        BinaryColor::Off,
+                    )?;
+                }
+            }
+
+            next_pos.x += advance as i32;
+        }
+
+        Ok(next_pos)
+    }
+
+    /// Draws the `rows` of the glyph at `area` in the font image.
+    ///
+    /// Only the edge pixels of the glyph are drawn as `On` for hollow glyphs. Flipped glyphs are
+    /// rotated by 180°.
+    fn draw_glyph<D>(
+        &self,
+        font: &MultiMonoFont<'a>,
+        area: &Rectangle,
+        rows: Rectangle,
+        position: Point,
+        flipped: bool,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = BinaryColor>,
+    {
+        if !self.hollow && !flipped {
+            return Image::new(&font.sub_image(rows), position).draw(target);
+        }
+
+        let is_on = |p: Point| area.contains(p) && font.image.pixel(p) == Some(BinaryColor::On);
+        let neighbors = [
+            Point::new(-1, 0),
+            Point::new(1, 0),
+            Point::new(0, -1),
+            Point::new(0, 1),
+        ];
+
+        let last = Point::new(rows.size.width as i32 - 1, rows.size.height as i32 - 1);
+        let source = |offset: Point| {
+            if flipped {
+                rows.top_left + (last - offset)
+            } else {
+                rows.top_left + offset
+            }
+        };
+
+        target.fill_contiguous(
+            &Rectangle::new(position, rows.size),
+            Rectangle::new(Point::zero(), rows.size)
+                .points()
+                .map(|offset| {
+                    let p = source(offset);
+                    let on = is_on(p)
+                        && (!self.hollow || neighbors.iter().any(|&offset| !is_on(p + offset)));
+
+                    BinaryColor::from(on)
+                }),
+        )
+    }
+
+    /// Draws only the glyph pixels of a combining character, which keeps the glyph below intact.
+    fn draw_mark<D>(
+        &self,
+        font: &MultiMonoFont<'a>,
+        c: char,
+        position: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = BinaryColor>,
+    {
+        let area = font.glyph_atlas_rect(c);
+
+        for p in area.points() {
+            if font.image.pixel(p) == Some(BinaryColor::On) {
+                target.fill_solid(
+                    &Rectangle::new(position + (p - area.top_left), Size::new(1, 1)),
+                    BinaryColor::On,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the character is drawn over the preceding glyph cell.
+    fn is_combining(&self, c: char) -> bool {
+        self.combining_chars.contains(&c)
+    }
+
+    /// Returns the width of a glyph cell, without the character spacing.
+    fn cell_width(&self, c: char, font: &MultiMonoFont<'a>) -> u32 {
+        let width = font.character_size.width as u32;
+
+        match (self.replacement_advance, self.min_advance) {
+            (Some(replacement_advance), _) if !font.contains(c) => (replacement_advance as u32)
+                .saturating_sub(font.character_spacing as u32)
+                .max(width),
+            (_, Some(min_advance)) => width.max(min_advance as u32),
+            _ => width,
+        }
+    }
+
+    /// Returns the horizontal advance of a glyph, including the character spacing.
+    ///
+    /// `x` is the pen position relative to the start of the line.
+    fn char_advance(&self, c: char, font: &MultiMonoFont<'a>, x: u32) -> u32 {
+        if self.is_combining(c) {
+            return 0;
+        }
+
+        if c == '\t' {
+            if let Some(advance) = self.tab_advance(x) {
+                return advance;
+            }
+        }
+
+        if let Some(width) = self.space_variant_width(c, font) {
+            let space_advance = self.cell_width(' ', font) + self.spacing(font);
+            return (space_advance * width / 2).max(1);
+        }
+
+        match self.replacement_advance {
+            Some(replacement_advance) if !font.contains(c) => replacement_advance as u32,
+            _ => self.cell_advance(c, font),
+        }
+    }
+
+    /// Returns the advance of a glyph cell, including the character and letter spacing.
+    fn cell_advance(&self, c: char, font: &MultiMonoFont<'a>) -> u32 {
+        let advance = self.cell_width(c, font) + self.spacing(font);
+        (advance as i32 + self.letter_spacing as i32).max(1) as u32
+    }
+
+    /// Measures a single line of text at the given position in its line.
+    fn measure_in_line(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        line: LineOffset,
+    ) -> TextMetrics {
+        let indent = match line {
+            LineOffset::Start => self.first_glyph_indent,
+            LineOffset::Continue { .. } => 0,
+        };
+        let mut bb_width = indent;
+        let mut bb_height = 0;
+        let mut baseline_max = 0;
+        let mut font = self.fonts[0];
+        for (c, glyph_font, advance) in self.layout_advances(text, line) {
+            if !self.is_combining(c) {
+                font = glyph_font;
+            }
+            bb_width += advance;
+            bb_height = bb_height.max(font.character_size.height as u32);
+
+            baseline_max = baseline_max.max(self.baseline_offset(baseline, font, c));
+        }
+        if bb_width > indent {
+            let trailing_spacing = self.spacing(font) as i32 + self.letter_spacing as i32;
+            bb_width = (bb_width as i32 - trailing_spacing).max(indent as i32) as u32;
+        }
+
+        let bb_size = Size::new(bb_width, bb_height);
+
+        let bb_position = position - Point::new(0, baseline_max);
+        let bounding_box = Rectangle::new(bb_position, bb_size);
+        let next_position = position + bb_size.x_axis();
+
+        match self.shrink.get() {
+            1 => TextMetrics {
+                bounding_box,
+                next_position,
+            },
+            factor => TextMetrics {
+                bounding_box: shrink_rect(&bounding_box, position, factor),
+                next_position: self.shrink_advance(position, next_position),
+            },
+        }
+    }
+
+    /// Rounds `position` down to a multiple of the grid.
+    fn snap_to_grid(&self, position: Point) -> Point {
+        match self.grid_snap as i32 {
+            0 | 1 => position,
+            grid => Point::new(
+                position.x - position.x.rem_euclid(grid),
+                position.y - position.y.rem_euclid(grid),
+            ),
+        }
+    }
+
+    /// Divides the advance from `position` to `next_position` by the downscaling factor.
+    ///
+    /// The advance of non-empty text is at least one pixel.
+    fn shrink_advance(&self, position: Point, next_position: Point) -> Point {
+        let advance = next_position.x - position.x;
+        let shrunk = advance / self.shrink.get() as i32;
+
+        Point::new(
+            position.x + if advance > 0 { shrunk.max(1) } else { shrunk },
+            next_position.y,
+        )
+    }
+
+    /// Returns the advance of a tab character at the pen position `x`, relative to the start of
+    /// the line, if tab stops are set.
+    fn tab_advance(&self, x: u32) -> Option<u32> {
+        match self.tab_stops {
+            Some(tab_stops) if tab_stops > 0 => Some(tab_stops - x % tab_stops),
+            _ => None,
+        }
+    }
+
+    /// Returns the width of a Unicode space variant in halves of the width of a normal space, if
+    /// the font doesn't contain a glyph for the character.
+    fn space_variant_width(&self, c: char, font: &MultiMonoFont<'a>) -> Option<u32> {
+        space_variant_width(c).filter(|_| !font.contains(c))
+    }
+
+    /// Returns `true` if the character is drawn as an empty cell.
+    fn is_blank(&self, c: char, font: &MultiMonoFont<'a>) -> bool {
+        (c == ' ' && self.space_follows_context)
+            || (c == '\t' && (self.tab_advance(0).is_some() || self.indent_tab_width.is_some()))
+            || self.space_variant_width(c, font).is_some()
+    }
+
+    /// Returns the spacing after a glyph cell.
+    fn spacing(&self, font: &MultiMonoFont<'a>) -> u32 {
+        let scale = self
+            .font_index(font)
+            .and_then(|index| self.font_spacing_scales.get(index))
+            .map_or(1, |scale| *scale as u32);
+        let character_spacing = font.character_spacing as u32 * scale;
+
+        match self.min_advance {
+            Some(_) => character_spacing + self.column_spacing as u32,
+            None => character_spacing,
+        }
+    }
+
+    /// Returns the offset of the middle of a glyph or line of the given height from its top edge.
+    fn middle(&self, height: ChSzTy) -> i32 {
+        if self.middle_round_nearest {
+            (height / 2) as i32
+        } else {
+            (height.saturating_sub(1) / 2) as i32
+        }
+    }
+
+    /// Returns the vertical offset between the line position and the top edge of the line and
+    /// the height of the line.
+    ///
+    /// The background of the whole line is filled if the style has a background color.
+    pub(crate) fn line_band(&self, baseline: Baseline) -> (i32, u32) {
+        let offset = match baseline {
+            Baseline::Top => 0,
+            Baseline::Bottom => self.line_height.saturating_sub(1) as i32,
+            Baseline::Middle => self.middle(self.line_height),
+            Baseline::Alphabetic => self
+                .fonts
+                .iter()
+                .map(|font| font.baseline as i32)
+                .max()
+                .unwrap_or_default(),
+        };
+
+        (offset, self.line_height as u32)
+    }
+
+    /// Returns the vertical offset between the line position and the top edge of the bounding box.
+    ///
+    /// Glyphs which aren't aligned by the baseline are positioned relative to the line band.
+    fn baseline_offset(&self, baseline: Baseline, font: &MultiMonoFont<'a>, c: char) -> i32 {
+        let height = font.character_size.height;
+
+        match (self.vertical_align, baseline) {
+            (VerticalGlyphAlign::CenterInLine, _) => {
+                self.line_band(baseline).0 - (self.line_height as i32 - height as i32) / 2
+            }
+            (VerticalGlyphAlign::TopOfLine, _) => self.line_band(baseline).0,
+            (VerticalGlyphAlign::Baseline, Baseline::Top) => 0,
+            (VerticalGlyphAlign::Baseline, Baseline::Bottom) => height.saturating_sub(1) as i32,
+            (VerticalGlyphAlign::Baseline, Baseline::Middle) => self.middle(height),
+            (VerticalGlyphAlign::Baseline, Baseline::Alphabetic) => font.glyph_baseline(c) as i32,
+        }
+    }
+}
+
+impl<C> MultiMonoTextStyle<'_, C>
+where
+    C: PixelColor + Into<Rgb888> + From<Rgb888>,
+{
+    /// Draws a single line of text and fades the glyphs near the edges of the draw target.
+    ///
+    /// `fade` contains the widths of the fade zones at the left and right edges, which are
+    /// measured from the edges of the bounding box of the draw target. Pixels inside the fade zones
+    /// are linearly blended toward the background color, or black if the style has no background
+    /// color. This is useful for scrolling tickers.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::Rgb888, prelude::*, text::Baseline,
+    /// };
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_4X6], MultiMonoLineHeight::Max, Rgb888::WHITE);
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let text = "||||||||||||||||";
+    /// style
+    ///     .draw_string_faded(text, Point::zero(), Baseline::Top, (16, 16), &mut display)
+    ///     .unwrap();
+    ///
+    /// let brightness = |x| display.get_pixel(Point::new(x, 2)).unwrap().r();
+    /// assert!(brightness(5) < brightness(9));
+    /// assert!(brightness(9) < brightness(33));
+    /// assert_eq!(brightness(33), 255);
+    /// assert!(brightness(61) < brightness(33));
+    /// ```
+    pub fn draw_string_faded<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        fade: (u32, u32),
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let background_color = self.background_color.unwrap_or(Rgb888::BLACK.into());
+        let mut target = EdgeFadeDrawTarget::new(target, fade, background_color);
+
+        self.draw_string(text, position, baseline, &mut target)
+    }
+
+    /// Draws a single line of text which is blended with the content of the draw target.
+    ///
+    /// The drawn pixels are blended with the pixels of the draw target according to `opacity`,
+    /// `0` is fully transparent and `255` is opaque. This requires a draw target which can be read
+    /// back, like a framebuffer.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     image::GetPixel,
+    ///     pixelcolor::{
+    ///         raw::{BigEndian, RawU24},
+    ///         Rgb888,
+    ///     },
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// type Display = Framebuffer<Rgb888, RawU24, BigEndian, 8, 9, { buffer_size::<Rgb888>(8, 9) }>;
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, Rgb888::RED);
+    ///
+    /// let mut opaque = Display::new();
+    /// Text::with_baseline("I", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut opaque)
+    ///     .unwrap();
+    ///
+    /// let mut translucent = Display::new();
+    /// translucent.clear(Rgb888::BLUE).unwrap();
+    /// style
+    ///     .draw_string_translucent("I", Point::zero(), Baseline::Top, 128, &mut translucent)
+    ///     .unwrap();
+    ///
+    /// for point in opaque.bounding_box().points() {
+    ///     let expected = match opaque.pixel(point) {
+    ///         Some(Rgb888::RED) => Rgb888::new(127, 0, 127),
+    ///         _ => Rgb888::BLUE,
+    ///     };
+    ///     assert_eq!(translucent.pixel(point), Some(expected));
+    /// }
+    /// ```
+    pub fn draw_string_translucent<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        opacity: u8,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C> + GetPixel<Color = C>,
+    {
+        match opacity {
+            u8::MAX => self.draw_string(text, position, baseline, target),
+            opacity => {
+                let mut target = OpacityDrawTarget::new(target, opacity);
+
+                self.draw_string(text, position, baseline, &mut target)
+            }
+        }
+    }
+
+    /// Draws a single line of text with experimental sub-pixel rendering for RGB stripe displays.
+    ///
+    /// The red channel of each pixel is taken from the glyph column on its left, the green channel
+    /// from its own column and the blue channel from the column on its right. Glyph edges are
+    /// smoothed by the subpixels of the neighboring pixels, which extends the drawn area by one
+    /// pixel on both sides. This requires a draw target which can be read back, like a
+    /// framebuffer. The background is drawn first, like by [`draw_string`].
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     image::GetPixel,
+    ///     pixelcolor::{
+    ///         raw::{BigEndian, RawU24},
+    ///         Rgb888,
+    ///     },
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// type Display = Framebuffer<Rgb888, RawU24, BigEndian, 8, 9, { buffer_size::<Rgb888>(8, 9) }>;
+    ///
+    /// let builder = MultiMonoTextStyleBuilder::new(Rgb888::WHITE)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .background_color(Rgb888::BLACK);
+    ///
+    /// let mut plain = Display::new();
+    /// Text::with_baseline("|", Point::new(1, 0), builder.build(), Baseline::Top)
+    ///     .draw(&mut plain)
+    ///     .unwrap();
+    ///
+    /// let mut subpixel = Display::new();
+    /// builder
+    ///     .build()
+    ///     .draw_string_subpixel("|", Point::new(1, 0), Baseline::Top, &mut subpixel)
+    ///     .unwrap();
+    ///
+    /// let on = |x, y| plain.pixel(Point::new(x, y)) == Some(Rgb888::WHITE);
+    /// let channel = |on| if on { 255 } else { 0 };
+    /// for point in subpixel.bounding_box().points() {
+    ///     let Point { x, y } = point;
+    ///     let expected = Rgb888::new(
+    ///         channel(on(x - 1, y)),
+    ///         channel(on(x, y)),
+    ///         channel(on(x + 1, y)),
+    ///     );
+    ///     assert_eq!(subpixel.pixel(point), Some(expected));
+    /// }
+    ///
+    /// // the edges of the bar are drawn by different channels
+    /// assert_ne!(subpixel, plain);
+    /// ```
+    ///
+    /// [`draw_string`]: TextRenderer::draw_string
+    pub fn draw_string_subpixel<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C> + GetPixel<Color = C>,
+    {
+        if let Some(background) = self.background_only() {
+            background.draw_string(text, position, baseline, target)?;
+        }
+
+        let foreground = self.foreground_only();
+        let mut next_position = position;
+        for (channel, offset) in [(Channel::Red, 1), (Channel::Green, 0), (Channel::Blue, -1)] {
+            let mut target = ChannelDrawTarget::new(target, channel, offset);
+            next_position = foreground.draw_string(text, position, baseline, &mut target)?;
+        }
+
+        Ok(next_position)
+    }
+}
+
+impl MultiMonoTextStyle<'_, BinaryColor> {
+    /// Draws a single line of text with the given blend mode.
+    ///
+    /// With [`BlendMode::Xor`] each pixel which is drawn as `On` inverts the pixel of the draw
+    /// target, which requires a draw target which can be read back, like a framebuffer.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     framebuffer::{buffer_size, Framebuffer},
+    ///     image::GetPixel,
+    ///     pixelcolor::{
+    ///         raw::{BigEndian, RawU1},
+    ///         BinaryColor,
+    ///     },
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    ///     text::Baseline,
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, BlendMode, MultiMonoLineHeight, MultiMonoTextStyle};
+    ///
+    /// type Display =
+    ///     Framebuffer<BinaryColor, RawU1, BigEndian, 8, 9, { buffer_size::<BinaryColor>(8, 9) }>;
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    ///
+    /// let original = || {
+    ///     let mut display = Display::new();
+    ///     Rectangle::new(Point::zero(), Size::new(3, 9))
+    ///         .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+    ///         .draw(&mut display)
+    ///         .unwrap();
+    ///     display
+    /// };
+    ///
+    /// let mut cursor = Display::new();
+    /// style
+    ///     .draw_string_blended("I", Point::zero(), Baseline::Top, BlendMode::Xor, &mut cursor)
+    ///     .unwrap();
+    ///
+    /// let mut display = original();
+    /// style
+    ///     .draw_string_blended("I", Point::zero(), Baseline::Top, BlendMode::Xor, &mut display)
+    ///     .unwrap();
+    /// for point in display.bounding_box().points() {
+    ///     let expected = BinaryColor::from(original().pixel(point) != cursor.pixel(point));
+    ///     assert_eq!(display.pixel(point), Some(expected));
+    /// }
+    ///
+    /// // drawing the cursor a second time restores the original content
+    /// style
+    ///     .draw_string_blended("I", Point::zero(), Baseline::Top, BlendMode::Xor, &mut display)
+    ///     .unwrap();
+    /// assert_eq!(display.data(), original().data());
+    /// ```
+    pub fn draw_string_blended<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        blend: BlendMode,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = BinaryColor> + GetPixel<Color = BinaryColor>,
+    {
+        match blend {
+            BlendMode::Replace => self.draw_string(text, position, baseline, target),
+            BlendMode::Xor => {
+                let mut target = XorDrawTarget::new(target);
+
+                self.draw_string(text, position, baseline, &mut target)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<C: ::defmt::Format> ::defmt::Format for MultiMonoTextStyle<'_, C> {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(
+            f,
+            "MultiMonoTextStyle {{ text_color: {}, background_color: {}, fonts: {}, line_height: {}, replacement_fn: ?, clip_warning: ?, .. }}",
+            &self.text_color,
+            &self.background_color,
+            &self.fonts,
+            &self.line_height,
+        )
+    }
+}
+
+impl<C> TextRenderer for MultiMonoTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    /// Draws a single line of text.
+    ///
+    /// If the style has a background color, the background is filled for the whole line height,
+    /// even if the glyphs are shorter than the line.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X12, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X12], MultiMonoLineHeight::Specify(20))
+    ///     .background_color(BinaryColor::Off)
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// style
+    ///     .draw_string("a", Point::zero(), Baseline::Top, &mut display)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(display.affected_area().size, Size::new(6, 20));
+    /// assert_eq!(display.get_pixel(Point::new(0, 19)), Some(BinaryColor::Off));
+    /// ```
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let position = self.snap_to_grid(position);
+
+        if cfg!(debug_assertions) {
+            if let Some(clip_warning) = self.clip_warning {
+                let bounding_box = self.measure_string(text, position, baseline).bounding_box;
+                if !bounding_box.is_zero_sized()
+                    && target.bounding_box().intersection(&bounding_box) != bounding_box
+                {
+                    clip_warning(bounding_box);
+                }
+            }
+        }
+
+        self.draw_line(text, position, baseline, LineOffset::Start, target)
+    }
+
+    /// Draws whitespace of the given width.
+    ///
+    /// The background is filled for the whole line height, like the background of the glyphs
+    /// drawn by [`draw_string`]. This results in a background bar of uniform height for text runs
+    /// which are followed by whitespace.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X12, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X12], MultiMonoLineHeight::Specify(20))
+    ///     .background_color(BinaryColor::Off)
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let next = style
+    ///     .draw_string("a", Point::new(0, 15), Baseline::Alphabetic, &mut display)
+    ///     .unwrap();
+    /// style
+    ///     .draw_whitespace(6, next, Baseline::Alphabetic, &mut display)
+    ///     .unwrap();
+    ///
+    /// let area = display.affected_area();
+    /// assert_eq!(area.size, Size::new(12, 20));
+    /// assert!(area.points().all(|point| display.get_pixel(point).is_some()));
+    /// ```
+    ///
+    /// [`draw_string`]: TextRenderer::draw_string
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let (offet_y, height) = self.line_band(baseline);
+        let (offet_y, height) = match self.shrink.get() {
+            1 => (offet_y, height),
+            factor => {
+                let band = Rectangle::new(position - Point::new(0, offet_y), Size::new(1, height));
+                let band = shrink_rect(&band, position, factor);
+                (position.y - band.top_left.y, band.size.height)
+            }
+        };
+        let position = position - Point::new(0, offet_y);
+
+        if width != 0 {
+            if let Some(background_color) = self.background_color {
+                target.fill_solid(
+                    &Rectangle::new(position, Size::new(width, height)),
+                    background_color,
+                )?;
+            }
+        }
+
+        Ok(position + Point::new(width as i32, offet_y))
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let position = self.snap_to_grid(position);
+
+        self.measure_in_line(text, position, baseline, LineOffset::Start)
+    }
+
+    fn line_height(&self) -> u32 {
+        (self.line_height as u32).div_ceil(self.shrink.get() as u32)
+    }
+}
+
+impl<C> CharacterStyle for MultiMonoTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        if let Some(color) = text_color {
+            self.text_color = color;
+        }
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+}
+
+/// Text style builder for monospaced fonts.
+///
+/// Use this builder to create [`MultiMonoTextStyle`]s for [`Text`].
+///
+/// # Examples
+///
+/// ## Render yellow text on a blue background
+///
+/// This uses the [`FONT_6X9`] font, but [other fonts] can also be used.
+///
+/// ```rust
+/// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::Text,
+/// };
+///
+/// let style = MultiMonoTextStyleBuilder::new(Rgb565::YELLOW)
+///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+///     .background_color(Rgb565::BLUE)
+///     .build();
+///
+/// let text = Text::new("Hello Rust!", Point::new(0, 0), style);
+/// ```
+///
+/// ## Transparent background
+///
+/// If a property is omitted, it will remain at its default value in the resulting
+/// `MultiMonoTextStyle` returned by `.build()`. This example draws white text with no background at
+/// all.
+///
+/// ```rust
+/// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::Text,
+/// };
+///
+/// let style = MultiMonoTextStyleBuilder::new(Rgb565::WHITE)
+///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+///     .build();
+///
+/// let text = Text::new("Hello Rust!", Point::new(0, 0), style);
+/// ```
+///
+/// ## Modifying an existing style
+///
+/// The builder can also be used to modify an existing style.
+///
+/// ```
+/// use multi_mono_font::{
+///     ascii::{FONT_10X20, FONT_6X9},
+///     MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+/// };
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::Text,
+/// };
+///
+/// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, Rgb565::YELLOW);
+///
+/// let style_larger = MultiMonoTextStyleBuilder::from(&style)
+///     .font(&[&FONT_10X20], MultiMonoLineHeight::Max)
+///     .build();
+/// ```
+///
+/// [`FONT_6X9`]: crate::ascii::FONT_6X9
+/// [other fonts]: super
+/// [`Text`]: crate::text::Text
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct MultiMonoTextStyleBuilder<'a, C> {
+    style: MultiMonoTextStyle<'a, C>,
+}
+
+impl<'a, C> MultiMonoTextStyleBuilder<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a new text style builder.
+    pub const fn new(text_color: C) -> Self {
+        Self {
+            style: MultiMonoTextStyle {
+                fonts: &[&super::NULL_FONT],
+                background_color: None,
+                text_color,
+                line_height: 0,
+                space_follows_context: false,
+                tab_expansion: None,
+                tab_stops: None,
+                indent_tab_width: None,
+                replacement_fn: None,
+                replacement_str: None,
+                replacement_font: None,
+                min_advance: None,
+                column_spacing: 0,
+                letter_spacing: 0,
+                replacement_advance: None,
+                bidi_auto: false,
+                reverse_chars: false,
+                ligatures: &[],
+                ignore_chars: &[],
+                clip_rows: None,
+                glyph_cache: false,
+                shrink: NonZeroU8::MIN,
+                fg_pattern: &[],
+                hollow: false,
+                flip_chars: &[],
+                vertical_align: VerticalGlyphAlign::Baseline,
+                middle_round_nearest: false,
+                first_glyph_indent: 0,
+                clip_warning: None,
+                fallback: None,
+                grid_snap: 0,
+                font_background_colors: &[],
+                font_spacing_scales: &[],
+                combining_chars: &[],
+                combining_y_offset: 0,
+                font_tie_break: FontTieBreak::FirstInSlice,
+                color_pass: ColorPass::All,
+            },
+        }
+    }
+
+    /// Sets the font.
+    ///
+    /// Borrowed options like the ligatures are kept, so they must live at least as long as the new
+    /// fonts.
+    pub const fn font<'b>(
+        self,
+        font_list: &'b [&'b MultiMonoFont<'b>],
+        line_height: MultiMonoLineHeight,
+    ) -> MultiMonoTextStyleBuilder<'b, C>
+    where
+        'a: 'b,
+    {
+        let fonts = if font_list.is_empty() {
+            &[&crate::NULL_FONT]
+        } else {
+            font_list
+        };
+        let line_height = get_line_height(line_height, fonts);
+        let style = MultiMonoTextStyle {
+            fonts,
+            background_color: self.style.background_color,
+            text_color: self.style.text_color,
+            line_height,
+            space_follows_context: self.style.space_follows_context,
+            tab_expansion: self.style.tab_expansion,
+            tab_stops: self.style.tab_stops,
+            indent_tab_width: self.style.indent_tab_width,
+            replacement_fn: self.style.replacement_fn,
+            replacement_str: self.style.replacement_str,
+            replacement_font: self.style.replacement_font,
+            min_advance: self.style.min_advance,
+            column_spacing: self.style.column_spacing,
+            letter_spacing: self.style.letter_spacing,
+            replacement_advance: self.style.replacement_advance,
+            bidi_auto: self.style.bidi_auto,
+            reverse_chars: self.style.reverse_chars,
+            ligatures: self.style.ligatures,
+            ignore_chars: self.style.ignore_chars,
+            clip_rows: self.style.clip_rows,
+            glyph_cache: self.style.glyph_cache,
+            shrink: self.style.shrink,
+            fg_pattern: self.style.fg_pattern,
+            hollow: self.style.hollow,
+            flip_chars: self.style.flip_chars,
+            vertical_align: self.style.vertical_align,
+            middle_round_nearest: self.style.middle_round_nearest,
+            first_glyph_indent: self.style.first_glyph_indent,
+            clip_warning: self.style.clip_warning,
+            fallback: self.style.fallback,
+            grid_snap: self.style.grid_snap,
+            font_background_colors: self.style.font_background_colors,
+            font_spacing_scales: self.style.font_spacing_scales,
+            combining_chars: self.style.combining_chars,
+            combining_y_offset: self.style.combining_y_offset,
+            font_tie_break: self.style.font_tie_break,
+            color_pass: self.style.color_pass,
+        };
+
+        MultiMonoTextStyleBuilder { style }
+    }
+
+    /// Resets the background color to transparent.
+    pub const fn reset_background_color(mut self) -> Self {
+        self.style.background_color = None;
+
+        self
+    }
+
+    /// Sets the text color.
+    pub const fn text_color(mut self, text_color: C) -> Self {
+        self.style.text_color = text_color;
+
+        self
+    }
+
+    /// Sets the line height.
+    pub const fn line_height(mut self, line_height: MultiMonoLineHeight) -> Self {
+        self.style.line_height = get_line_height(line_height, self.style.fonts);
+
+        self
+    }
+
+    /// Sets the line height, clamped to the range `min..=max`.
+    ///
+    /// This can be used to limit the line height of [`MultiMonoLineHeight::Max`] if one font is
+    /// much taller than the others.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::BinaryColor, text::renderer::TextRenderer};
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_10X20, FONT_6X9},
+    ///     CharSize, MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const LARGE_FONT: MultiMonoFont = MultiMonoFont {
+    ///     character_size: CharSize::new(10, 40),
+    ///     ..FONT_10X20
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9, &LARGE_FONT], MultiMonoLineHeight::Max)
+    ///     .line_height_clamped(MultiMonoLineHeight::Max, 12, 24)
+    ///     .build();
+    /// assert_eq!(style.line_height(), 24);
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .line_height_clamped(MultiMonoLineHeight::Max, 12, 24)
+    ///     .build();
+    /// assert_eq!(style.line_height(), 12);
+    /// ```
+    pub const fn line_height_clamped(
+        mut self,
+        line_height: MultiMonoLineHeight,
+        min: ChSzTy,
+        max: ChSzTy,
+    ) -> Self {
+        let line_height = get_line_height(line_height, self.style.fonts);
+        self.style.line_height = if line_height < min {
+            min
+        } else if line_height > max {
+            max
+        } else {
+            line_height
+        };
+
+        self
+    }
+
+    /// Sets the background color.
+    pub const fn background_color(mut self, background_color: C) -> Self {
+        self.style.background_color = Some(background_color);
+
+        self
+    }
+
+    /// Sets the text color if `text_color` is `Some`.
+    ///
+    /// This makes it possible to set a color conditionally, e.g. depending on the state of a UI
+    /// element, without branching. The text color is left unchanged for `None`.
+    pub const fn maybe_text_color(mut self, text_color: Option<C>) -> Self {
+        if let Some(text_color) = text_color {
+            self.style.text_color = text_color;
+        }
+
+        self
+    }
+
+    /// Sets the background color if `background_color` is `Some`.
+    ///
+    /// The background color is left unchanged for `None`.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = |selected: bool| {
+    ///     MultiMonoTextStyleBuilder::new(Rgb565::WHITE)
+    ///         .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///         .maybe_text_color(selected.then_some(Rgb565::BLACK))
+    ///         .maybe_background_color(selected.then_some(Rgb565::WHITE))
+    ///         .build()
+    /// };
+    ///
+    /// let normal = style(false);
+    /// assert_eq!(normal.text_color, Rgb565::WHITE);
+    /// assert_eq!(normal.background_color, None);
+    ///
+    /// let selected = style(true);
+    /// assert_eq!(selected.text_color, Rgb565::BLACK);
+    /// assert_eq!(selected.background_color, Some(Rgb565::WHITE));
+    /// ```
+    pub const fn maybe_background_color(mut self, background_color: Option<C>) -> Self {
+        if let Some(background_color) = background_color {
+            self.style.background_color = Some(background_color);
+        }
+
+        self
+    }
+
+    /// Sets whether spaces use the font of the surrounding text.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_10X20, FONT_6X9},
+    ///     mapping::StrGlyphMapping,
+    ///     MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const CJK_FONT: MultiMonoFont = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("字", 0),
+    ///     ..FONT_10X20
+    /// };
+    ///
+    /// let builder = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9, &CJK_FONT], MultiMonoLineHeight::Max);
+    ///
+    /// let style = builder.build();
+    /// assert!(style.char_offsets("字 a").eq([10, 16, 22]));
+    ///
+    /// let style = builder.space_follows_context(true).build();
+    /// assert!(style.char_offsets("字 a").eq([10, 20, 26]));
+    /// ```
+    pub const fn space_follows_context(mut self, space_follows_context: bool) -> Self {
+        self.style.space_follows_context = space_follows_context;
+
+        self
+    }
+
+    /// Sets the number of spaces a tab character is expanded to.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, text::Text,
+    /// };
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_4X6], MultiMonoLineHeight::Max)
+    ///     .background_color(BinaryColor::Off)
+    ///     .tab_expansion(Some(4))
+    ///     .build();
+    /// assert!(style.char_offsets("a\tb").eq([4, 8, 12, 16, 20, 24]));
+    ///
+    /// let mut expanded = MockDisplay::new();
+    /// Text::new("a\tb", Point::new(0, 5), style)
+    ///     .draw(&mut expanded)
+    ///     .unwrap();
+    ///
+    /// let mut spaces = MockDisplay::new();
+    /// Text::new("a    b", Point::new(0, 5), style)
+    ///     .draw(&mut spaces)
+    ///     .unwrap();
+    ///
+    /// expanded.assert_eq(&spaces);
+    /// ```
+    pub const fn tab_expansion(mut self, tab_expansion: Option<u8>) -> Self {
+        self.style.tab_expansion = tab_expansion;
+
+        self
+    }
+
+    /// Sets the distance between tab stops.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_4X6], MultiMonoLineHeight::Max)
+    ///     .tab_stops(Some(16))
+    ///     .build();
+    /// assert!(style.char_offsets("a\tb\tc").eq([4, 16, 20, 32, 36]));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let next = style
+    ///     .draw_string("a\tb", Point::zero(), Baseline::Top, &mut display)
+    ///     .unwrap();
+    /// let metrics = style.measure_string("a\tb", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.next_position, next);
+    /// ```
+    pub const fn tab_stops(mut self, tab_stops: Option<u32>) -> Self {
+        self.style.tab_stops = tab_stops;
+
+        self
+    }
+
+    /// Sets the advance of tabs at the start of a line.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .indent_tab_width(Some(8))
+    ///     .tab_stops(Some(24))
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("\t\tcode", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// let plain = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("code", Point::new(2 * 8, 0), plain, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    ///
+    /// // tabs after the first glyph advance to the next tab stop
+    /// let metrics = style.measure_string("\tx\t", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size.width, 24);
+    ///
+    /// // tabs at the start of each line are leading tabs
+    /// assert!(style.char_offsets("\tx\n\ty").eq([8, 14, 20, 28, 34]));
+    /// ```
+    pub const fn indent_tab_width(mut self, indent_tab_width: Option<u32>) -> Self {
+        self.style.indent_tab_width = indent_tab_width;
+
+        self
+    }
+
+    /// Sets the replacement function for missing glyphs.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, text::Text,
+    /// };
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// fn straight_quotes(c: char) -> char {
+    ///     match c {
+    ///         '“' | '”' => '"',
+    ///         c => c,
+    ///     }
+    /// }
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_4X6], MultiMonoLineHeight::Max)
+    ///     .replacement_fn(Some(straight_quotes))
+    ///     .build();
+    ///
+    /// let mut curly = MockDisplay::new();
+    /// Text::new("“a”", Point::new(0, 5), style)
+    ///     .draw(&mut curly)
+    ///     .unwrap();
+    ///
+    /// let mut straight = MockDisplay::new();
+    /// Text::new("\"a\"", Point::new(0, 5), style)
+    ///     .draw(&mut straight)
+    ///     .unwrap();
+    ///
+    /// curly.assert_eq(&straight);
+    /// ```
+    pub const fn replacement_fn(mut self, replacement_fn: Option<fn(char) -> char>) -> Self {
+        self.style.replacement_fn = replacement_fn;
+
+        self
+    }
+
+    /// Sets the replacement string for missing glyphs.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .replacement_str(Some("[?]"))
+    ///     .build();
+    ///
+    /// let metrics = style.measure_string("a\u{1F600}b", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size.width, 5 * 6);
+    ///
+    /// let mut emoji = MockDisplay::new();
+    /// Text::with_baseline("a\u{1F600}b", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut emoji)
+    ///     .unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("a[?]b", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// emoji.assert_eq(&expected);
+    /// ```
+    pub const fn replacement_str(mut self, replacement_str: Option<&'a str>) -> Self {
+        self.style.replacement_str = replacement_str;
+
+        self
+    }
+
+    /// Sets the replacement font for missing glyphs.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::ImageRaw,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, mapping::StrGlyphMapping, CharSize, MultiMonoFont, MultiMonoLineHeight,
+    ///     MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const TOFU: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::new(&[0b1100_0000, 0b1100_0000], 2),
+    ///     &StrGlyphMapping::new("?", 0),
+    ///     CharSize::new(2, 2),
+    ///     0,
+    ///     1,
+    /// );
+    ///
+    /// const STYLE: MultiMonoTextStyle<BinaryColor> =
+    ///     MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///         .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///         .replacement_font(&TOFU)
+    ///         .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("\u{1F600}", Point::zero(), STYLE, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// display.assert_pattern(&[
+    ///     "##", //
+    ///     "##", //
+    /// ]);
+    /// ```
+    pub const fn replacement_font(mut self, replacement_font: &'a MultiMonoFont<'a>) -> Self {
+        self.style.replacement_font = Some(replacement_font);
+
+        self
+    }
+
+    /// Sets the minimum glyph advance.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, text::Text,
+    /// };
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_4X6], MultiMonoLineHeight::Max)
+    ///     .min_advance(Some(8))
+    ///     .build();
+    /// assert!(style.char_offsets("||").eq([8, 16]));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::new("|", Point::new(0, 4), style)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// // the 4px wide glyph is centered in the 8px wide cell
+    /// display.assert_pattern(&[
+    ///     "   #", //
+    ///     "   #", //
+    ///     "   #", //
+    ///     "   #", //
+    ///     "   #", //
+    /// ]);
+    /// ```
+    pub const fn min_advance(mut self, min_advance: Option<ChSzTy>) -> Self {
+        self.style.min_advance = min_advance;
+
+        self
+    }
+
+    /// Sets the column spacing.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_4X6], MultiMonoLineHeight::Max)
+    ///     .min_advance(Some(6))
+    ///     .column_spacing(2)
+    ///     .build();
+    /// assert!(style.char_offsets("ab").eq([8, 16]));
+    /// ```
+    pub const fn column_spacing(mut self, column_spacing: ChSzTy) -> Self {
+        self.style.column_spacing = column_spacing;
+
+        self
+    }
+
+    /// Sets the letter spacing.
+    pub const fn letter_spacing(mut self, letter_spacing: i8) -> Self {
+        self.style.letter_spacing = letter_spacing;
+
+        self
+    }
+
+    /// Sets the advance of replaced glyphs.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{ascii::FONT_4X6, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_4X6], MultiMonoLineHeight::Max)
+    ///     .replacement_advance(Some(8))
+    ///     .build();
+    /// assert!(style.char_offsets("é字a").eq([8, 16, 20]));
+    /// ```
+    pub const fn replacement_advance(mut self, replacement_advance: Option<ChSzTy>) -> Self {
+        self.style.replacement_advance = replacement_advance;
+
+        self
+    }
+
+    /// Sets whether runs of right-to-left characters are reversed.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, text::Text,
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_4X6, mapping::StrGlyphMapping, MultiMonoFont, MultiMonoLineHeight,
+    ///     MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const HEBREW_FONT: MultiMonoFont = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("\0\u{5ce}\u{5d1}", 0),
+    ///     ..FONT_4X6
+    /// };
+    /// const FONTS: &[&MultiMonoFont] = &[&FONT_4X6, &HEBREW_FONT];
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(FONTS, MultiMonoLineHeight::Max)
+    ///     .bidi_auto(true)
+    ///     .build();
+    /// let mut bidi = MockDisplay::new();
+    /// Text::new("a\u{5d0}\u{5d1}b", Point::new(0, 5), style)
+    ///     .draw(&mut bidi)
+    ///     .unwrap();
+    ///
+    /// let style = MultiMonoTextStyle::new(FONTS, MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let mut reversed = MockDisplay::new();
+    /// Text::new("a\u{5d1}\u{5d0}b", Point::new(0, 5), style)
+    ///     .draw(&mut reversed)
+    ///     .unwrap();
+    ///
+    /// bidi.assert_eq(&reversed);
+    /// ```
+    pub const fn bidi_auto(mut self, bidi_auto: bool) -> Self {
+        self.style.bidi_auto = bidi_auto;
+
+        self
+    }
+
+    /// Enables or disables the reversed character order.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .reverse_chars(true)
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("abc", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// // 'c' is drawn at the leftmost position
+    /// let plain = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("cba", Point::zero(), plain, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub const fn reverse_chars(mut self, reverse_chars: bool) -> Self {
+        self.style.reverse_chars = reverse_chars;
+
+        self
+    }
+
+    /// Sets the ligatures.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, text::Text,
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_4X6, mapping::StrGlyphMapping, MultiMonoFont, MultiMonoLineHeight,
+    ///     MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// // draws the glyph for '#' for U+FB01 (LATIN SMALL LIGATURE FI)
+    /// const LIGATURE_FONT: MultiMonoFont = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("\0\u{fafe}\u{fb01}", 0),
+    ///     ..FONT_4X6
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_4X6, &LIGATURE_FONT], MultiMonoLineHeight::Max)
+    ///     .ligatures(&[("fi", '\u{fb01}')])
+    ///     .build();
+    /// assert!(style.char_offsets("fix").eq([4, 8]));
+    ///
+    /// let mut ligature = MockDisplay::new();
+    /// Text::new("fix", Point::new(0, 5), style)
+    ///     .draw(&mut ligature)
+    ///     .unwrap();
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_4X6], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let mut expected = MockDisplay::new();
+    /// Text::new("#x", Point::new(0, 5), style)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// ligature.assert_eq(&expected);
+    /// ```
+    pub const fn ligatures(mut self, ligatures: &'a [(&'a str, char)]) -> Self {
+        self.style.ligatures = ligatures;
+
+        self
+    }
+
+    /// Sets the characters which are removed from the text.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .ignore_chars(&['\u{feff}'])
+    ///     .build();
+    ///
+    /// let metrics = style.measure_string("\u{feff}AB", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.next_position, Point::new(12, 0));
+    ///
+    /// let mut bom = MockDisplay::new();
+    /// style
+    ///     .draw_string("\u{feff}AB", Point::zero(), Baseline::Top, &mut bom)
+    ///     .unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// style
+    ///     .draw_string("AB", Point::zero(), Baseline::Top, &mut expected)
+    ///     .unwrap();
+    ///
+    /// bom.assert_eq(&expected);
+    /// ```
+    pub const fn ignore_chars(mut self, ignore_chars: &'a [char]) -> Self {
+        self.style.ignore_chars = ignore_chars;
+
+        self
+    }
+
+    /// Sets the clipped glyph rows.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let builder = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max);
+    ///
+    /// let mut full = MockDisplay::new();
+    /// Text::with_baseline("A", Point::zero(), builder.build(), Baseline::Top)
+    ///     .draw(&mut full)
+    ///     .unwrap();
+    ///
+    /// let mut clipped = MockDisplay::new();
+    /// let style = builder.clip_rows(Some((0, 4))).build();
+    /// Text::with_baseline("A", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut clipped)
+    ///     .unwrap();
+    ///
+    /// for point in Rectangle::new(Point::zero(), Size::new(6, 9)).points() {
+    ///     if point.y < 4 {
+    ///         assert_eq!(clipped.get_pixel(point), full.get_pixel(point));
+    ///     } else {
+    ///         assert_eq!(clipped.get_pixel(point), None);
+    ///     }
+    /// }
+    /// ```
+    pub const fn clip_rows(mut self, clip_rows: Option<(ChSzTy, ChSzTy)>) -> Self {
+        self.style.clip_rows = clip_rows;
+
+        self
+    }
+
+    /// Enables or disables the glyph cache.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_4X6, FONT_6X9},
+    ///     mapping::StrGlyphMapping,
+    ///     MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const DIGITS: MultiMonoFont = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("0123456789", 0),
+    ///     ..FONT_6X9
+    /// };
+    ///
+    /// let builder = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&DIGITS, &FONT_4X6], MultiMonoLineHeight::Max)
+    ///     .background_color(BinaryColor::Off);
+    ///
+    /// for text in ["aaaaaaaaaaaaaaa", "a1a1b2c3a1"] {
+    ///     let mut uncached = MockDisplay::new();
+    ///     Text::with_baseline(text, Point::zero(), builder.build(), Baseline::Top)
+    ///         .draw(&mut uncached)
+    ///         .unwrap();
+    ///
+    ///     let mut cached = MockDisplay::new();
+    ///     let style = builder.glyph_cache(true).build();
+    ///     Text::with_baseline(text, Point::zero(), style, Baseline::Top)
+    ///         .draw(&mut cached)
+    ///         .unwrap();
+    ///
+    ///     cached.assert_eq(&uncached);
+    /// }
+    /// ```
+    pub const fn glyph_cache(mut self, glyph_cache: bool) -> Self {
+        self.style.glyph_cache = glyph_cache;
+
+        self
+    }
+
+    /// Sets the downscaling factor.
+    ///
+    /// ```
+    /// use core::num::NonZeroU8;
+    /// use embedded_graphics::{
+    ///     image::ImageRaw,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{
+    ///     mapping::StrGlyphMapping, CharSize, MultiMonoFont, MultiMonoLineHeight,
+    ///     MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const FONT_24: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::new(&[0xFF; 72], 24),
+    ///     &StrGlyphMapping::new("字", 0),
+    ///     CharSize::new(24, 24),
+    ///     0,
+    ///     24,
+    /// );
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_24], MultiMonoLineHeight::Max)
+    ///     .shrink(NonZeroU8::new(2).unwrap())
+    ///     .build();
+    /// assert_eq!(style.line_height(), 12);
+    ///
+    /// let metrics = style.measure_string("字", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size, Size::new(12, 12));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let next = style
+    ///     .draw_string("字", Point::zero(), Baseline::Top, &mut display)
+    ///     .unwrap();
+    /// assert_eq!(next, Point::new(12, 0));
+    /// assert_eq!(display.affected_area().size, Size::new(12, 12));
+    /// ```
+    pub const fn shrink(mut self, shrink: NonZeroU8) -> Self {
+        self.style.shrink = shrink;
+
+        self
+    }
+
+    /// Sets the foreground pattern.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(Rgb565::WHITE)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .fg_pattern(&[Rgb565::RED, Rgb565::BLUE])
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("H", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// // the left stem of the H is drawn in alternating colors
+    /// let stem = [1, 2, 3, 4].map(|y| display.get_pixel(Point::new(1, y)));
+    /// assert_eq!(
+    ///     stem,
+    ///     [Rgb565::BLUE, Rgb565::RED, Rgb565::BLUE, Rgb565::RED].map(Some)
+    /// );
+    /// ```
+    pub const fn fg_pattern(mut self, fg_pattern: &'a [C]) -> Self {
+        self.style.fg_pattern = fg_pattern;
+
+        self
+    }
+
+    /// Enables or disables hollow glyphs.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::ImageRaw,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     mapping::StrGlyphMapping, CharSize, MultiMonoFont, MultiMonoLineHeight,
+    ///     MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const BLOCK: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::new(&[0b1111_0000; 4], 4),
+    ///     &StrGlyphMapping::new("#", 0),
+    ///     CharSize::new(4, 4),
+    ///     0,
+    ///     3,
+    /// );
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&BLOCK], MultiMonoLineHeight::Max)
+    ///     .background_color(BinaryColor::Off)
+    ///     .hollow(true)
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("#", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// display.assert_pattern(&[
+    ///     "####", //
+    ///     "#..#", //
+    ///     "#..#", //
+    ///     "####", //
+    /// ]);
+    /// ```
+    pub const fn hollow(mut self, hollow: bool) -> Self {
+        self.style.hollow = hollow;
+
+        self
+    }
+
+    /// Sets the upside-down characters.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let builder = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max);
+    ///
+    /// let mut upright = MockDisplay::new();
+    /// Text::with_baseline("aba", Point::zero(), builder.build(), Baseline::Top)
+    ///     .draw(&mut upright)
+    ///     .unwrap();
+    ///
+    /// let mut flipped = MockDisplay::new();
+    /// let style = builder.flip_chars(&['b']).build();
+    /// Text::with_baseline("aba", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut flipped)
+    ///     .unwrap();
+    ///
+    /// for y in 0..9 {
+    ///     for x in 0..6 {
+    ///         // the `a`s are upright
+    ///         for cell_x in [0, 12] {
+    ///             let point = Point::new(cell_x + x, y);
+    ///             assert_eq!(flipped.get_pixel(point), upright.get_pixel(point));
+    ///         }
+    ///
+    ///         // the `b` is rotated by 180°
+    ///         assert_eq!(
+    ///             flipped.get_pixel(Point::new(6 + x, y)),
+    ///             upright.get_pixel(Point::new(6 + 5 - x, 8 - y))
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub const fn flip_chars(mut self, flip_chars: &'a [char]) -> Self {
+        self.style.flip_chars = flip_chars;
+
+        self
+    }
+
+    /// Sets the vertical alignment of glyphs inside the line.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X12, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    ///     VerticalGlyphAlign,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X12], MultiMonoLineHeight::Specify(24))
+    ///     .vertical_align(VerticalGlyphAlign::CenterInLine)
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("A", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// // the 12px glyph is centered in the 24px line
+    /// let plain = MultiMonoTextStyle::new(&[&FONT_6X12], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("A", Point::new(0, 6), plain, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub const fn vertical_align(mut self, vertical_align: VerticalGlyphAlign) -> Self {
+        self.style.vertical_align = vertical_align;
+
+        self
+    }
+
+    /// Sets whether the middle baseline is rounded to the nearest pixel.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X12, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let glyph_top = |middle_round_nearest| {
+    ///     MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///         .font(&[&FONT_6X12], MultiMonoLineHeight::Max)
+    ///         .middle_round_nearest(middle_round_nearest)
+    ///         .build()
+    ///         .measure_string("A", Point::new(0, 10), Baseline::Middle)
+    ///         .bounding_box
+    ///         .top_left
+    ///         .y
+    /// };
+    ///
+    /// // the 12 pixel high glyph is centered on the middle baseline
+    /// assert_eq!(glyph_top(false), 10 - 5);
+    /// assert_eq!(glyph_top(true), 10 - 6);
+    /// ```
+    pub const fn middle_round_nearest(mut self, middle_round_nearest: bool) -> Self {
+        self.style.middle_round_nearest = middle_round_nearest;
+
+        self
+    }
+
+    /// Sets the indent before the first glyph.
+    ///
+    /// The measured width includes the indent, but not the spacing after the last glyph.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .letter_spacing(1)
+    ///     .first_glyph_indent(5)
+    ///     .build();
+    ///
+    /// // indent + 2 advances - trailing spacing
+    /// let metrics = style.measure_string("ab", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.size.width, 5 + 2 * 7 - 1);
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("ab", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// let plain = MultiMonoTextStyleBuilder::from(&style)
+    ///     .first_glyph_indent(0)
+    ///     .build();
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("ab", Point::new(5, 0), plain, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub const fn first_glyph_indent(mut self, first_glyph_indent: u32) -> Self {
+        self.style.first_glyph_indent = first_glyph_indent;
+
+        self
+    }
+
+    /// Sets the diagnostic function for clipped text.
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicBool, Ordering};
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, primitives::Rectangle,
+    ///     text::Text,
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// static CLIPPED: AtomicBool = AtomicBool::new(false);
+    ///
+    /// fn warn_clipped(_bounding_box: Rectangle) {
+    ///     CLIPPED.store(true, Ordering::Relaxed);
+    /// }
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .clip_warning(Some(warn_clipped))
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// display.set_allow_out_of_bounds_drawing(true);
+    ///
+    /// Text::new("Hi", Point::new(0, 10), style).draw(&mut display).unwrap();
+    /// assert!(!CLIPPED.load(Ordering::Relaxed));
+    ///
+    /// Text::new("Hi", Point::new(60, 10), style).draw(&mut display).unwrap();
+    /// assert_eq!(CLIPPED.load(Ordering::Relaxed), cfg!(debug_assertions));
+    /// ```
+    pub const fn clip_warning(mut self, clip_warning: Option<fn(Rectangle)>) -> Self {
+        self.style.clip_warning = clip_warning;
+
+        self
+    }
+
+    /// Sets the pixel grid of the text origin.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .grid_snap(2)
+    ///     .build();
+    ///
+    /// let metrics = style.measure_string("Hi", Point::new(3, 0), Baseline::Top);
+    /// assert_eq!(metrics.bounding_box.top_left, Point::new(2, 0));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("Hi", Point::new(3, 0), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// let plain = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("Hi", Point::new(2, 0), plain, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub const fn grid_snap(mut self, grid_snap: ChSzTy) -> Self {
+        self.style.grid_snap = grid_snap;
+
+        self
+    }
+
+    /// Sets the background colors of the fonts.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     image::ImageRaw,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_4X6, FONT_6X9},
+    ///     mapping::StrGlyphMapping,
+    ///     CharSize, FontTieBreak, MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const CJK: MultiMonoFont = MultiMonoFont::new(
+    ///     ImageRaw::new(&[0b1000_0000, 0b0000_0000], 2),
+    ///     &StrGlyphMapping::new("\u{5B57}", 0),
+    ///     CharSize::new(2, 2),
+    ///     0,
+    ///     1,
+    /// );
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(Rgb565::WHITE)
+    ///     .font(&[&FONT_6X9, &CJK], MultiMonoLineHeight::Max)
+    ///     .font_background_colors(&[Some(Rgb565::BLACK), Some(Rgb565::BLUE)])
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("a\u{5B57}", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::BLACK));
+    /// assert_eq!(display.get_pixel(Point::new(6, 0)), Some(Rgb565::WHITE));
+    /// assert_eq!(display.get_pixel(Point::new(7, 0)), Some(Rgb565::BLUE));
+    ///
+    /// // the background colors don't change the layout
+    /// let style = MultiMonoTextStyleBuilder::from(&style)
+    ///     .tab_stops(Some(16))
+    ///     .build();
+    /// let text = "a\u{5B57}\tb";
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let next = style
+    ///     .draw_string(text, Point::zero(), Baseline::Top, &mut display)
+    ///     .unwrap();
+    /// assert_eq!(next, Point::new(22, 0));
+    /// assert_eq!(
+    ///     style.measure_string(text, Point::zero(), Baseline::Top).next_position,
+    ///     next
+    /// );
+    ///
+    /// let reversed = MultiMonoTextStyleBuilder::from(&style)
+    ///     .reverse_chars(true)
+    ///     .build();
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("a\u{5B57}", Point::zero(), reversed, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    /// assert_eq!(display.get_pixel(Point::new(1, 0)), Some(Rgb565::BLUE));
+    /// assert_eq!(display.get_pixel(Point::new(2, 0)), Some(Rgb565::BLACK));
+    ///
+    /// // the background color of the font which is selected by the tie-break policy is used
+    /// let style = MultiMonoTextStyleBuilder::new(Rgb565::WHITE)
+    ///     .font(&[&FONT_4X6, &FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .font_tie_break(FontTieBreak::LargestCell)
+    ///     .font_background_colors(&[Some(Rgb565::RED), Some(Rgb565::BLUE)])
+    ///     .build();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Text::with_baseline("a", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut display)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(display.affected_area().size, Size::new(6, 9));
+    /// assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Rgb565::BLUE));
+    /// ```
+    pub const fn font_background_colors(mut self, font_background_colors: &'a [Option<C>]) -> Self {
+        self.style.font_background_colors = font_background_colors;
+
+        self
+    }
+
+    /// Sets the character spacing multipliers of the fonts.
+    ///
+    /// ```
+    /// use embedded_graphics::pixelcolor::BinaryColor;
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_10X20, FONT_6X9},
+    ///     mapping::StrGlyphMapping,
+    ///     MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// const ASCII: MultiMonoFont = MultiMonoFont {
+    ///     character_spacing: 1,
+    ///     ..FONT_6X9
+    /// };
+    /// const CJK: MultiMonoFont = MultiMonoFont {
+    ///     glyph_mapping: &StrGlyphMapping::new("\u{5B57}", 0),
+    ///     character_spacing: 1,
+    ///     ..FONT_10X20
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&ASCII, &CJK], MultiMonoLineHeight::Max)
+    ///     .font_spacing_scales(&[1, 3])
+    ///     .build();
+    ///
+    /// // CJK glyphs are followed by 3 pixels of spacing, ASCII glyphs by 1 pixel
+    /// assert!(style
+    ///     .char_offsets("ab\u{5B57}\u{5B57}")
+    ///     .eq([7, 14, 27, 40]));
+    /// ```
+    pub const fn font_spacing_scales(mut self, font_spacing_scales: &'a [u8]) -> Self {
+        self.style.font_spacing_scales = font_spacing_scales;
+
+        self
+    }
+
+    /// Sets the combining characters and their vertical offset.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder};
+    ///
+    /// let plain = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .build();
+    /// let style = MultiMonoTextStyleBuilder::from(&plain)
+    ///     .combining_chars(&['`'], -1)
+    ///     .build();
+    ///
+    /// let metrics = style.measure_string("a`", Point::zero(), Baseline::Top);
+    /// assert_eq!(metrics.next_position, Point::new(6, 0));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// let next = style
+    ///     .draw_string("a`", Point::new(0, 1), Baseline::Top, &mut display)
+    ///     .unwrap();
+    /// assert_eq!(next, Point::new(6, 1));
+    ///
+    /// // the mark is drawn one pixel above its regular position in the cell of the `a`
+    /// let mut expected = MockDisplay::new();
+    /// plain
+    ///     .draw_string("a", Point::new(0, 1), Baseline::Top, &mut expected)
+    ///     .unwrap();
+    /// plain
+    ///     .draw_string("`", Point::new(0, 0), Baseline::Top, &mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub const fn combining_chars(mut self, combining_chars: &'a [char], y_offset: i8) -> Self {
+        self.style.combining_chars = combining_chars;
+        self.style.combining_y_offset = y_offset;
+
+        self
+    }
+
+    /// Sets the font selection for characters which are contained in multiple fonts.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{renderer::TextRenderer, Baseline},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::{FONT_10X20, FONT_6X9},
+    ///     FontTieBreak, MultiMonoLineHeight, MultiMonoTextStyleBuilder,
+    /// };
+    ///
+    /// let width = |fonts, font_tie_break| {
+    ///     MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///         .font(fonts, MultiMonoLineHeight::Max)
+    ///         .font_tie_break(font_tie_break)
+    ///         .build()
+    ///         .measure_string(".", Point::zero(), Baseline::Top)
+    ///         .bounding_box
+    ///         .size
+    ///         .width
+    /// };
+    ///
+    /// // both fonts contain the `.`
+    /// let fonts = &[&FONT_6X9, &FONT_10X20];
+    /// assert_eq!(width(fonts, FontTieBreak::FirstInSlice), 6);
+    /// assert_eq!(width(fonts, FontTieBreak::LargestCell), 10);
+    ///
+    /// let fonts = &[&FONT_10X20, &FONT_6X9];
+    /// assert_eq!(width(fonts, FontTieBreak::FirstInSlice), 10);
+    /// assert_eq!(width(fonts, FontTieBreak::SmallestCell), 6);
+    /// ```
+    pub const fn font_tie_break(mut self, font_tie_break: FontTieBreak) -> Self {
+        self.style.font_tie_break = font_tie_break;
 
         self
     }
@@ -0,0 +1,595 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    text::{renderer::TextRenderer, Baseline},
+    transform::Transform,
+    Drawable,
+};
+
+use crate::{static_text::ellipsis, wrap::WrapIter, ChSzTy};
+
+/// Paragraph drawable.
+///
+/// A paragraph draws text which is wrapped to a maximum width. Lines are broken at explicit line
+/// breaks and at spaces, words which don't fit into a single line are split between characters.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Paragraph<'a, S> {
+    /// The string.
+    pub text: &'a str,
+
+    /// The position of the first line.
+    pub position: Point,
+
+    /// The maximum line width.
+    pub width: u32,
+
+    /// The character style.
+    pub character_style: S,
+
+    /// Text baseline.
+    pub baseline: Baseline,
+
+    /// Height of empty lines.
+    ///
+    /// If set, empty lines advance by this height instead of the line height of the character
+    /// style. This makes it possible to separate paragraphs by `"\n\n"` without leaving a full
+    /// empty line. The [`line_gap`] is added to both heights, like in [`StaticText`].
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, Paragraph};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let paragraph = Paragraph {
+    ///     empty_line_height: Some(3),
+    ///     ..Paragraph::with_baseline("a\n\nb", Point::zero(), 64, style, Baseline::Top)
+    /// };
+    /// assert_eq!(paragraph.measure(), Size::new(6, 9 + 3 + 9));
+    ///
+    /// let with_gap = Paragraph {
+    ///     line_gap: 1,
+    ///     ..paragraph
+    /// };
+    /// assert_eq!(with_gap.measure(), Size::new(6, 9 + 1 + 3 + 1 + 9));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// paragraph.draw(&mut display).unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("a", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    /// Text::with_baseline("b", Point::new(0, 9 + 3), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    ///
+    /// [`line_gap`]: Paragraph::line_gap
+    /// [`StaticText`]: crate::StaticText
+    pub empty_line_height: Option<ChSzTy>,
+
+    /// Indent of the first line.
+    ///
+    /// The first line is offset to the right by this width and is wrapped to the remaining width.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, Paragraph};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let paragraph = Paragraph {
+    ///     first_line_indent: 12,
+    ///     hanging_indent: 6,
+    ///     ..Paragraph::with_baseline("aa bb cc", Point::zero(), 30, style, Baseline::Top)
+    /// };
+    /// assert_eq!(paragraph.measure(), Size::new(24, 27));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// paragraph.draw(&mut display).unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// let lines = [
+    ///     ("aa", Point::new(12, 0)),
+    ///     ("bb", Point::new(6, 9)),
+    ///     ("cc", Point::new(6, 18)),
+    /// ];
+    /// for (line, position) in lines {
+    ///     Text::with_baseline(line, position, style, Baseline::Top)
+    ///         .draw(&mut expected)
+    ///         .unwrap();
+    /// }
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub first_line_indent: u32,
+
+    /// Indent of all lines after the first line.
+    ///
+    /// The lines are offset to the right by this width and are wrapped to the remaining width.
+    pub hanging_indent: u32,
+
+    /// Justified lines.
+    ///
+    /// If `true`, the space between the words of wrapped lines is widened so that the lines
+    /// fill the full width. Lines which end at an explicit line break or at the end of the text
+    /// aren't justified.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, Paragraph};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let paragraph = Paragraph {
+    ///     justify: true,
+    ///     ..Paragraph::with_baseline("aaa bb c dddd", Point::zero(), 54, style, Baseline::Top)
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    /// paragraph.draw(&mut display).unwrap();
+    ///
+    /// // the 6px of extra space are distributed between the words of the first line
+    /// let mut expected = MockDisplay::new();
+    /// let words = [
+    ///     ("aaa", Point::new(0, 0)),
+    ///     ("bb", Point::new(24 + 3, 0)),
+    ///     ("c", Point::new(48, 0)),
+    ///     ("dddd", Point::new(0, 9)),
+    /// ];
+    /// for (word, position) in words {
+    ///     Text::with_baseline(word, position, style, Baseline::Top)
+    ///         .draw(&mut expected)
+    ///         .unwrap();
+    /// }
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub justify: bool,
+
+    /// Extra vertical space between lines.
+    ///
+    /// Consecutive lines are drawn `line_height + line_gap` pixels apart, where the line height
+    /// of empty lines is [`empty_line_height`] if it is set. No gap is added after the last line
+    /// by [`measure`].
+    ///
+    /// [`empty_line_height`]: Paragraph::empty_line_height
+    /// [`measure`]: Paragraph::measure
+    pub line_gap: u32,
+
+    /// Maximum number of lines.
+    ///
+    /// If set, only the first `max_lines` lines are drawn. If the text has more lines, the last
+    /// drawn line is shortened and ends with an ellipsis (`…`). Three periods are used instead if
+    /// none of the fonts contains `…`, like the bundled ASCII fonts.
+    ///
+    /// ```
+    /// use core::sync::atomic::{AtomicBool, Ordering};
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder, Paragraph,
+    /// };
+    ///
+    /// static CLIPPED: AtomicBool = AtomicBool::new(false);
+    ///
+    /// fn warn_clipped(_bounding_box: Rectangle) {
+    ///     CLIPPED.store(true, Ordering::Relaxed);
+    /// }
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .clip_warning(Some(warn_clipped))
+    ///     .build();
+    /// let paragraph = Paragraph {
+    ///     max_lines: Some(2),
+    ///     ..Paragraph::with_baseline(
+    ///         "aaa bbb ccc ddd eee",
+    ///         Point::zero(),
+    ///         30,
+    ///         style,
+    ///         Baseline::Top,
+    ///     )
+    /// };
+    /// assert_eq!(paragraph.measure(), Size::new(30, 18));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// paragraph.draw(&mut display).unwrap();
+    ///
+    /// // the periods of the ellipsis
+    /// for x in [14, 20, 26] {
+    ///     assert_eq!(display.get_pixel(Point::new(x, 15)), Some(BinaryColor::On));
+    /// }
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("aaa\nbb...", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    ///
+    /// // choosing the ellipsis doesn't report clipped text
+    /// assert!(!CLIPPED.load(Ordering::Relaxed));
+    /// ```
+    pub max_lines: Option<usize>,
+}
+
+/// Wrapped line of a paragraph.
+struct Line<'t> {
+    /// The text of the line.
+    text: &'t str,
+
+    /// Horizontal offset of the line.
+    indent: u32,
+
+    /// Extra space which is inserted between the words of justified lines.
+    slack: u32,
+
+    /// The ellipsis which follows the line, if the line is truncated.
+    ellipsis: Option<&'static str>,
+}
+
+impl<'a, S> Paragraph<'a, S> {
+    /// Creates a paragraph drawable with the default baseline.
+    pub const fn new(text: &'a str, position: Point, width: u32, character_style: S) -> Self {
+        Self {
+            text,
+            position,
+            width,
+            character_style,
+            baseline: Baseline::Alphabetic,
+            empty_line_height: None,
+            first_line_indent: 0,
+            hanging_indent: 0,
+            justify: false,
+            line_gap: 0,
+            max_lines: None,
+        }
+    }
+
+    /// Creates a paragraph drawable with the given baseline.
+    pub const fn with_baseline(
+        text: &'a str,
+        position: Point,
+        width: u32,
+        character_style: S,
+        baseline: Baseline,
+    ) -> Self {
+        Self {
+            text,
+            position,
+            width,
+            character_style,
+            baseline,
+            empty_line_height: None,
+            first_line_indent: 0,
+            hanging_indent: 0,
+            justify: false,
+            line_gap: 0,
+            max_lines: None,
+        }
+    }
+}
+
+impl<S: TextRenderer> Paragraph<'_, S> {
+    fn lines(&self) -> impl Iterator<Item = Line<'_>> {
+        let mut lines = WrapIter::new(self.text, &self.character_style, self.width)
+            .with_indents(self.first_line_indent, self.hanging_indent);
+        let mut indent = self.first_line_indent;
+        let mut remaining = self.max_lines;
+
+        core::iter::from_fn(move || {
+            if remaining == Some(0) {
+                return None;
+            }
+            remaining = remaining.map(|remaining| remaining - 1);
+
+            let (text, wrapped) = lines.next_line()?;
+            let line_indent = indent;
+            indent = self.hanging_indent;
+
+            if remaining == Some(0) && lines.next_line().is_some() {
+                let ellipsis = ellipsis(&self.character_style);
+
+                return Some(Line {
+                    text: self.truncate(text, ellipsis, self.width.saturating_sub(line_indent)),
+                    indent: line_indent,
+                    slack: 0,
+                    ellipsis: Some(ellipsis),
+                });
+            }
+
+            let slack = if self.justify && wrapped && Self::gaps(text) > 0 {
+                self.width
+                    .saturating_sub(line_indent + self.line_width(text))
+            } else {
+                0
+            };
+
+            Some(Line {
+                text,
+                indent: line_indent,
+                slack,
+                ellipsis: None,
+            })
+        })
+    }
+
+    /// Shortens a line until it fits into `width` together with the ellipsis.
+    fn truncate<'t>(&self, line: &'t str, ellipsis: &str, width: u32) -> &'t str {
+        let ellipsis_width = self.line_width(ellipsis);
+        let mut head = line.trim_end_matches(' ');
+
+        while !head.is_empty() && self.line_width(head) + ellipsis_width > width {
+            let end = head.char_indices().last().map_or(0, |(index, _)| index);
+            head = head[..end].trim_end_matches(' ');
+        }
+
+        head
+    }
+
+    fn line_width(&self, line: &str) -> u32 {
+        self.character_style
+            .measure_string(line, Point::zero(), self.baseline)
+            .bounding_box
+            .size
+            .width
+    }
+
+    /// Returns the number of gaps between the words of a line.
+    fn gaps(line: &str) -> u32 {
+        Self::words(line.trim_end_matches(' '))
+            .count()
+            .saturating_sub(1) as u32
+    }
+
+    /// Returns the words of a line, each followed by its trailing spaces.
+    ///
+    /// Leading spaces of the line are part of the first word.
+    fn words(line: &str) -> impl Iterator<Item = &str> {
+        let mut rest = line;
+
+        core::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+
+            let start = rest.len() - rest.trim_start_matches(' ').len();
+            let word_end = rest[start..]
+                .find(' ')
+                .map_or(rest.len(), |offset| start + offset);
+            let end = rest[word_end..]
+                .find(|c| c != ' ')
+                .map_or(rest.len(), |offset| word_end + offset);
+            let (word, tail) = rest.split_at(end);
+            rest = tail;
+
+            Some(word)
+        })
+    }
+
+    /// Returns the pen position in front of the character with the given index.
+    ///
+    /// The index counts characters, not bytes. Characters which aren't drawn, like spaces at wrap
+    /// positions and line breaks, are mapped to the end of the preceding line. Indices past the
+    /// end of the text are mapped to the end of the last line.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, text::Baseline};
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, Paragraph};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let paragraph =
+    ///     Paragraph::with_baseline("aaa bbb ccc", Point::zero(), 30, style, Baseline::Top);
+    ///
+    /// // the second 'b' in the second line
+    /// assert_eq!(paragraph.position_of(5), Point::new(6, 9));
+    /// // the space at the wrap position
+    /// assert_eq!(paragraph.position_of(3), Point::new(18, 0));
+    /// ```
+    pub fn position_of(&self, char_index: usize) -> Point {
+        let offset = self
+            .text
+            .char_indices()
+            .nth(char_index)
+            .map_or(self.text.len(), |(index, _)| index);
+
+        let mut line_position = self.position;
+        let mut position = self.position;
+        for line in self.lines() {
+            let start = line.text.as_ptr() as usize - self.text.as_ptr() as usize;
+            if start > offset {
+                break;
+            }
+
+            let end = (offset - start).min(line.text.len());
+            position =
+                line_position + Point::new((line.indent + self.advance_to(&line, end)) as i32, 0);
+            line_position.y += self.line_advance(line.text) as i32;
+        }
+
+        position
+    }
+
+    /// Returns the horizontal offset of the byte offset `end` in a line.
+    fn advance_to(&self, line: &Line<'_>, end: usize) -> u32 {
+        let text = line.text;
+        let x = match text[end..].chars().next() {
+            // the left edge of the next character
+            Some(c) => {
+                let next = &text[end..end + c.len_utf8()];
+                self.line_width(&text[..end + c.len_utf8()])
+                    .saturating_sub(self.line_width(next))
+            }
+            None => self.line_width(text),
+        };
+
+        let gaps = Self::gaps(text);
+        if line.slack == 0 || gaps == 0 {
+            return x;
+        }
+
+        // extra space is inserted in front of each word after the first word
+        let bytes = text.as_bytes();
+        let passed = (1..=end.min(bytes.len() - 1))
+            .filter(|&i| bytes[i - 1] == b' ' && bytes[i] != b' ')
+            .filter(|&i| bytes[..i].iter().any(|&b| b != b' '))
+            .count() as u32;
+
+        x + passed * (line.slack / gaps) + passed.min(line.slack % gaps)
+    }
+
+    /// Returns the height of the given line.
+    fn line_height(&self, line: &str) -> u32 {
+        match self.empty_line_height {
+            Some(height) if line.is_empty() => height as u32,
+            _ => self.character_style.line_height(),
+        }
+    }
+
+    /// Returns the vertical advance after the given line.
+    fn line_advance(&self, line: &str) -> u32 {
+        self.line_height(line) + self.line_gap
+    }
+
+    /// Returns the size of the wrapped text.
+    ///
+    /// The width is the width of the widest line including its indent. The height is the sum of
+    /// the line heights and the [`line_gap`] between consecutive lines, no gap is added after the
+    /// last line.
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, Paragraph};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let paragraph = Paragraph::new("aaa bbb ccc", Point::zero(), 30, style);
+    /// assert_eq!(paragraph.measure(), Size::new(18, 27));
+    ///
+    /// let paragraph = Paragraph {
+    ///     line_gap: 2,
+    ///     ..paragraph
+    /// };
+    /// assert_eq!(paragraph.measure(), Size::new(18, 3 * 9 + 2 * 2));
+    /// ```
+    ///
+    /// [`line_gap`]: Paragraph::line_gap
+    pub fn measure(&self) -> Size {
+        let mut size = Size::zero();
+        let mut last_line = None;
+        for line in self.lines() {
+            let mut width = line.indent + self.line_width(line.text) + line.slack;
+            if let Some(ellipsis) = line.ellipsis {
+                width += self.line_width(ellipsis);
+            }
+            size.width = size.width.max(width);
+            if let Some(last_line) = last_line {
+                size.height += self.line_advance(last_line);
+            }
+            last_line = Some(line.text);
+        }
+        if let Some(last_line) = last_line {
+            size.height += self.line_height(last_line);
+        }
+
+        size
+    }
+}
+
+impl<S: Clone> Transform for Paragraph<'_, S> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..self.clone()
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+impl<S: TextRenderer> Drawable for Paragraph<'_, S> {
+    type Color = S::Color;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let mut position = self.position;
+        let mut next_position = position;
+
+        for line in self.lines() {
+            let line_position = position + Point::new(line.indent as i32, 0);
+            let slack = line.slack;
+            if slack == 0 {
+                next_position = self.character_style.draw_string(
+                    line.text,
+                    line_position,
+                    self.baseline,
+                    target,
+                )?;
+            } else {
+                let gaps = Self::gaps(line.text);
+                next_position = line_position;
+                for (index, word) in Self::words(line.text).enumerate() {
+                    next_position = self.character_style.draw_string(
+                        word,
+                        next_position,
+                        self.baseline,
+                        target,
+                    )?;
+
+                    let index = index as u32;
+                    if index < gaps {
+                        let extra = slack / gaps + u32::from(index < slack % gaps);
+                        self.character_style.draw_whitespace(
+                            extra,
+                            next_position,
+                            self.baseline,
+                            target,
+                        )?;
+                        next_position.x += extra as i32;
+                    }
+                }
+            }
+
+            if let Some(ellipsis) = line.ellipsis {
+                next_position = self.character_style.draw_string(
+                    ellipsis,
+                    next_position,
+                    self.baseline,
+                    target,
+                )?;
+            }
+
+            position.y += self.line_advance(line.text) as i32;
+        }
+
+        Ok(next_position)
+    }
+}
@@ -1,12 +1,94 @@
 use embedded_graphics::{
     draw_target::DrawTarget,
     geometry::Point,
-    primitives::Rectangle,
+    primitives::{Line, Primitive, PrimitiveStyle, Rectangle},
     text::{renderer::TextRenderer, Alignment, Baseline},
     transform::Transform,
     Drawable,
 };
 
+use crate::{
+    draw_target::{BoundsDrawTarget, FingerprintDrawTarget},
+    multi_mono_text_style::envelope,
+    ChSzTy,
+};
+
+/// Returns the ellipsis which replaces truncated text.
+///
+/// This is `…` if the character style has a glyph for it. Otherwise `…` would be drawn like any
+/// other missing character, which is detected by comparing it with a noncharacter, and three
+/// periods are used instead. The bounding box of the probe target contains the probed strings, so
+/// probing doesn't trigger clipping diagnostics of the character style.
+pub(crate) fn ellipsis<S: TextRenderer>(character_style: &S) -> &'static str {
+    let fingerprint = |text| {
+        let bounding_box = character_style
+            .measure_string(text, Point::zero(), Baseline::Top)
+            .bounding_box;
+        let mut target = FingerprintDrawTarget::new(bounding_box);
+        if let Err(never) =
+            character_style.draw_string(text, Point::zero(), Baseline::Top, &mut target)
+        {
+            match never {}
+        }
+        target.fingerprint()
+    };
+
+    if fingerprint("\u{2026}") == fingerprint("\u{FFFF}") {
+        "..."
+    } else {
+        "\u{2026}"
+    }
+}
+
+/// Text overflow behavior.
+///
+/// Defines how lines which are wider than the rectangle of a [`StaticText`] are drawn.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Overflow {
+    /// Lines are drawn completely, even if they extend outside the rectangle.
+    Visible,
+
+    /// The end of the line is replaced by an ellipsis (`…`, or `...` if no font contains `…`).
+    Ellipsis,
+
+    /// The middle of the line is replaced by an ellipsis (`…`, or `...` if no font contains `…`).
+    ///
+    /// The head and the tail of the line are kept, which is useful for file paths and names.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Alignment, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, Overflow, StaticText,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let rect = Rectangle::new(Point::zero(), Size::new(60, 9));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// StaticText {
+    ///     overflow: Overflow::MiddleEllipsis,
+    ///     ..StaticText::with_style("documentfile.txt", rect, style, Alignment::Left, Baseline::Top)
+    /// }
+    /// .draw(&mut display)
+    /// .unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("docu...txt", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    MiddleEllipsis,
+}
+
 /// StaticText drawable.
 ///
 /// A text drawable can be used to draw text to a draw target.
@@ -29,6 +111,165 @@ pub struct StaticText<'a, S> {
 
     /// Text baseline.
     pub baseline: Baseline,
+
+    /// Text overflow behavior.
+    pub overflow: Overflow,
+
+    /// Extra vertical space between lines.
+    ///
+    /// Consecutive lines are drawn `line_height + line_gap` pixels apart. The gap isn't filled
+    /// with the background color.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Alignment, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, StaticText};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let rect = Rectangle::new(Point::zero(), Size::new(12, 30));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// StaticText {
+    ///     line_gap: 4,
+    ///     ..StaticText::with_style("ab\ncd", rect, style, Alignment::Left, Baseline::Top)
+    /// }
+    /// .draw(&mut display)
+    /// .unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("ab", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    /// Text::with_baseline("cd", Point::new(0, 9 + 4), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub line_gap: u32,
+
+    /// Padding between the left edge of the rectangle and the text.
+    ///
+    /// The text is aligned and truncated inside the rectangle without the left and right
+    /// padding. The padding is filled with the background color, like the rest of the line.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Alignment, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, StaticText};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let rect = Rectangle::new(Point::zero(), Size::new(30, 9));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// StaticText {
+    ///     padding_left: 4,
+    ///     padding_right: 2,
+    ///     ..StaticText::with_style("ab", rect, style, Alignment::Left, Baseline::Top)
+    /// }
+    /// .draw(&mut display)
+    /// .unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("ab", Point::new(4, 0), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    pub padding_left: u32,
+
+    /// Padding between the text and the right edge of the rectangle.
+    pub padding_right: u32,
+
+    /// Fill the whitespace before and after each line.
+    ///
+    /// If `true`, which is the default, the parts of the rectangle to the left and the right of
+    /// each line are filled with the background color of the character style. Disable this to
+    /// draw into a region which is shared with other content.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Alignment, Baseline},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder, StaticText,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .background_color(BinaryColor::Off)
+    ///     .build();
+    /// let rect = Rectangle::new(Point::zero(), Size::new(30, 9));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// StaticText {
+    ///     fill_line_background: false,
+    ///     ..StaticText::with_style("ab", rect, style, Alignment::Left, Baseline::Top)
+    /// }
+    /// .draw(&mut display)
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     display.affected_area(),
+    ///     Rectangle::new(Point::zero(), Size::new(12, 9))
+    /// );
+    /// ```
+    pub fill_line_background: bool,
+
+    /// Height of empty lines.
+    ///
+    /// If set, empty lines advance by this height instead of the line height of the character
+    /// style. The [`line_gap`] is added to both heights.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Alignment, Baseline, Text},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, StaticText};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let rect = Rectangle::new(Point::zero(), Size::new(6, 30));
+    ///
+    /// let mut display = MockDisplay::new();
+    /// StaticText {
+    ///     empty_line_height: Some(3),
+    ///     ..StaticText::with_style("a\n\nb", rect, style, Alignment::Left, Baseline::Top)
+    /// }
+    /// .draw(&mut display)
+    /// .unwrap();
+    ///
+    /// let mut expected = MockDisplay::new();
+    /// Text::with_baseline("a", Point::zero(), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    /// Text::with_baseline("b", Point::new(0, 9 + 3), style, Baseline::Top)
+    ///     .draw(&mut expected)
+    ///     .unwrap();
+    ///
+    /// display.assert_eq(&expected);
+    /// ```
+    ///
+    /// [`line_gap`]: StaticText::line_gap
+    pub empty_line_height: Option<ChSzTy>,
 }
 
 impl<'a, S> StaticText<'a, S> {
@@ -40,6 +281,12 @@ impl<'a, S> StaticText<'a, S> {
             character_style,
             alignment: Alignment::Left,
             baseline: Baseline::Alphabetic,
+            overflow: Overflow::Visible,
+            line_gap: 0,
+            padding_left: 0,
+            padding_right: 0,
+            fill_line_background: true,
+            empty_line_height: None,
         }
     }
 
@@ -57,6 +304,12 @@ impl<'a, S> StaticText<'a, S> {
             character_style,
             alignment,
             baseline,
+            overflow: Overflow::Visible,
+            line_gap: 0,
+            padding_left: 0,
+            padding_right: 0,
+            fill_line_background: true,
+            empty_line_height: None,
         }
     }
 }
@@ -77,11 +330,206 @@ impl<S: Clone> Transform for StaticText<'_, S> {
 }
 
 impl<S: TextRenderer> StaticText<'_, S> {
-    fn lines(&self) -> impl Iterator<Item = (&str, Point)> {
-        let line_feed = self.text.matches('\n').count() as i32;
+    /// Returns the width of `text`.
+    fn width(&self, text: &str) -> u32 {
+        self.character_style
+            .measure_string(text, Point::zero(), self.baseline)
+            .bounding_box
+            .size
+            .width
+    }
+
+    /// Returns the left edge and the width of the rectangle without the padding.
+    fn content_area(&self) -> (i32, u32) {
+        let left_x = self.rectangle.top_left.x + self.padding_left as i32;
+        let width = self
+            .rectangle
+            .size
+            .width
+            .saturating_sub(self.padding_left + self.padding_right);
+
+        (left_x, width)
+    }
+
+    /// Splits a line into the parts before and after the ellipsis.
+    ///
+    /// The second part is `None` if the line isn't truncated, otherwise it contains the ellipsis
+    /// and the tail of the line. The ellipsis is stored in `ellipsis_cache` when it is needed for
+    /// the first time.
+    fn truncate<'t>(
+        &self,
+        line: &'t str,
+        ellipsis_cache: &mut Option<&'static str>,
+    ) -> (&'t str, Option<(&'static str, &'t str)>) {
+        let (_, max_width) = self.content_area();
+        if self.overflow == Overflow::Visible || self.width(line) <= max_width {
+            return (line, None);
+        }
+
+        let ellipsis = *ellipsis_cache.get_or_insert_with(|| ellipsis(&self.character_style));
+        let char_offset = |n| line.char_indices().nth(n).map_or(line.len(), |(i, _)| i);
+        let ellipsis_width = self.width(ellipsis);
+        let count = line.chars().count();
+
+        for visible in (0..count).rev() {
+            let tail_count = match self.overflow {
+                Overflow::MiddleEllipsis => visible / 2,
+                _ => 0,
+            };
+            let head = &line[..char_offset(visible - tail_count)];
+            let tail = &line[char_offset(count - tail_count)..];
+
+            if self.width(head) + ellipsis_width + self.width(tail) <= max_width {
+                return (head, Some((ellipsis, tail)));
+            }
+        }
+
+        ("", Some((ellipsis, "")))
+    }
+
+    /// Returns `true` if any line is truncated by the overflow behavior.
+    ///
+    /// Lines are never truncated with [`Overflow::Visible`].
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Alignment, Baseline},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, Overflow, StaticText,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+    /// let rect = Rectangle::new(Point::zero(), Size::new(30, 9));
+    /// let text = |text| StaticText {
+    ///     overflow: Overflow::Ellipsis,
+    ///     ..StaticText::with_style(text, rect, style, Alignment::Left, Baseline::Top)
+    /// };
+    ///
+    /// assert!(!text("short").is_truncated());
+    /// assert!(text("overflowing").is_truncated());
+    /// ```
+    pub fn is_truncated(&self) -> bool {
+        self.lines().any(|(_, tail, _)| tail.is_some())
+    }
+
+    /// Returns the area which is affected by drawing the text.
+    ///
+    /// The area is the union of the bounding boxes of all lines, including the alignment offsets.
+    /// Each line is extended to the left and right edges of the rectangle if the whitespace next
+    /// to the line is filled, see [`fill_line_background`]. Empty lines are only included if
+    /// their whitespace is filled, i.e. if the character style has a background color. This can
+    /// be used to clear the text before it is redrawn.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Alignment, Baseline},
+    /// };
+    /// use multi_mono_font::{
+    ///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyleBuilder, StaticText,
+    /// };
+    ///
+    /// let style = MultiMonoTextStyleBuilder::new(BinaryColor::On)
+    ///     .font(&[&FONT_6X9], MultiMonoLineHeight::Max)
+    ///     .background_color(BinaryColor::Off)
+    ///     .build();
+    /// let rect = Rectangle::new(Point::new(2, 3), Size::new(60, 30));
+    /// let text = StaticText::with_style("ab\nabcd", rect, style, Alignment::Right, Baseline::Top);
+    ///
+    /// let dirty_rect = text.dirty_rect();
+    /// assert_eq!(dirty_rect.top_left, Point::new(2, 3));
+    /// assert_eq!(dirty_rect.size.height, 2 * 9);
+    ///
+    /// let mut display = MockDisplay::new();
+    /// text.draw(&mut display).unwrap();
+    /// assert_eq!(display.affected_area(), dirty_rect);
+    ///
+    /// // empty lines are filled with the background color
+    /// let rect = Rectangle::new(Point::zero(), Size::new(30, 30));
+    /// for text in ["\nab", "ab\n"] {
+    ///     let text = StaticText::with_style(text, rect, style, Alignment::Left, Baseline::Top);
+    ///     assert_eq!(
+    ///         text.dirty_rect(),
+    ///         Rectangle::new(Point::zero(), Size::new(30, 18))
+    ///     );
+    ///
+    ///     let mut display = MockDisplay::new();
+    ///     text.draw(&mut display).unwrap();
+    ///     assert_eq!(display.affected_area(), text.dirty_rect());
+    /// }
+    /// ```
+    ///
+    /// [`fill_line_background`]: StaticText::fill_line_background
+    pub fn dirty_rect(&self) -> Rectangle {
+        let left_x = self.rectangle.top_left.x;
+        let right_x = left_x + self.rectangle.size.width as i32;
 
-        let offset_y = self.character_style.line_height() as i32 * line_feed;
-        let mut position = self.rectangle.top_left;
+        self.lines()
+            .fold(Rectangle::zero(), |dirty_rect, (head, tail, position)| {
+                let mut line_box = Rectangle::zero();
+                let mut next_position = position;
+                let (ellipsis, tail) = tail.unzip();
+                for part in [Some(head), ellipsis, tail].into_iter().flatten() {
+                    let metrics =
+                        self.character_style
+                            .measure_string(part, next_position, self.baseline);
+                    line_box = envelope(&line_box, &metrics.bounding_box);
+                    next_position = metrics.next_position;
+                }
+
+                let line_box = match line_box.bottom_right() {
+                    Some(bottom_right) if self.fill_line_background => Rectangle::with_corners(
+                        Point::new(line_box.top_left.x.min(left_x), line_box.top_left.y),
+                        Point::new(bottom_right.x.max(right_x - 1), bottom_right.y),
+                    ),
+                    // empty lines are only affected by the whitespace fill
+                    None if self.fill_line_background => {
+                        let mut bounds = BoundsDrawTarget::new();
+                        let width = (right_x - left_x) as u32;
+                        let position = Point::new(left_x, position.y);
+                        match self.character_style.draw_whitespace(
+                            width,
+                            position,
+                            self.baseline,
+                            &mut bounds,
+                        ) {
+                            Ok(_) => bounds.bounds(),
+                            Err(never) => match never {},
+                        }
+                    }
+                    _ => line_box,
+                };
+
+                envelope(&dirty_rect, &line_box)
+            })
+    }
+
+    /// Returns the vertical distance between a line and the next line.
+    fn line_advance(&self, line: &str) -> i32 {
+        let height = match self.empty_line_height {
+            Some(height) if line.is_empty() => height as u32,
+            _ => self.character_style.line_height(),
+        };
+
+        (height + self.line_gap) as i32
+    }
+
+    fn lines(&self) -> impl Iterator<Item = (&str, Option<(&'static str, &str)>, Point)> {
+        let mut lines = self.text.split('\n');
+        // the advance of the last line doesn't contribute to the height of the text
+        lines.next_back();
+        let offset_y = lines
+            .map(|line| self.line_advance(line.strip_suffix('\r').unwrap_or(line)))
+            .sum::<i32>();
+        let (left_x, area_width) = self.content_area();
+        let mut position = Point::new(left_x, self.rectangle.top_left.y);
         let height = self.rectangle.size.height as i32;
         match self.baseline {
             Baseline::Top => {}
@@ -89,38 +537,103 @@ impl<S: TextRenderer> StaticText<'_, S> {
             Baseline::Middle => position.y += (height - 1 - offset_y) / 2,
         }
 
+        let mut ellipsis_cache = None;
         self.text.split('\n').map(move |line| {
+            // remove trailing '\r' for '\r\n' line endings
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let (head, tail) = self.truncate(line, &mut ellipsis_cache);
+
+            let width = match tail {
+                Some((ellipsis, tail)) => {
+                    self.width(head) + self.width(ellipsis) + self.width(tail)
+                }
+                None => self.width(head),
+            };
             let p = match self.alignment {
                 Alignment::Left => position,
                 Alignment::Right => {
-                    let metrics =
-                        self.character_style
-                            .measure_string(line, Point::zero(), self.baseline);
-                    position + Point::new(self.rectangle.size.width as i32, 0)
-                        - (metrics.next_position - Point::new(1, 0))
+                    position + Point::new(area_width as i32, 0) - Point::new(width as i32 - 1, 0)
                 }
                 Alignment::Center => {
-                    let metrics =
-                        self.character_style
-                            .measure_string(line, Point::zero(), self.baseline);
-                    position + Point::new(self.rectangle.size.width as i32 / 2, 0)
-                        - metrics.next_position / 2
+                    position + Point::new(area_width as i32 / 2, 0)
+                        - Point::new(width as i32 / 2, 0)
                 }
             };
 
-            position.y += self.character_style.line_height() as i32;
+            position.y += self.line_advance(line);
 
-            // remove trailing '\r' for '\r\n' line endings
-            let len = line.len();
-            if len > 0 && line.as_bytes()[len - 1] == b'\r' {
-                (&line[0..len - 1], p)
-            } else {
-                (line, p)
-            }
+            (head, tail, p)
         })
     }
 }
 
+impl<S: TextRenderer> StaticText<'_, S> {
+    /// Draws the text and a horizontal guide at the baseline of each line.
+    ///
+    /// The guides are one pixel high lines across the rectangle, which are drawn on top of the
+    /// text at the alphabetic baseline of each line. This can be used to verify the baseline
+    /// alignment of mixed fonts.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     primitives::Rectangle,
+    ///     text::{Alignment, Baseline},
+    /// };
+    /// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, StaticText};
+    ///
+    /// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, Rgb565::RED);
+    /// let rect = Rectangle::new(Point::zero(), Size::new(12, 18));
+    /// let text = StaticText::with_style("ab\ncd", rect, style, Alignment::Left, Baseline::Top);
+    ///
+    /// let mut display = MockDisplay::new();
+    /// display.set_allow_overdraw(true);
+    /// text.draw_with_baseline_guides(&mut display, Rgb565::GREEN)
+    ///     .unwrap();
+    ///
+    /// // the baseline of FONT_6X9 is 6 pixels below the top of the line
+    /// for y in [6, 9 + 6] {
+    ///     for x in 0..12 {
+    ///         assert_eq!(display.get_pixel(Point::new(x, y)), Some(Rgb565::GREEN));
+    ///     }
+    /// }
+    /// ```
+    pub fn draw_with_baseline_guides<D>(
+        &self,
+        target: &mut D,
+        color: S::Color,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = S::Color>,
+    {
+        let next_position = self.draw(target)?;
+
+        let left_x = self.rectangle.top_left.x;
+        let right_x = left_x + self.rectangle.size.width as i32 - 1;
+        let guide_style = PrimitiveStyle::with_stroke(color, 1);
+
+        for (head, _, position) in self.lines() {
+            // offset between the line position and the alphabetic baseline
+            let measure = |baseline| {
+                self.character_style
+                    .measure_string(head, position, baseline)
+                    .bounding_box
+                    .top_left
+                    .y
+            };
+            let y = position.y + measure(self.baseline) - measure(Baseline::Alphabetic);
+
+            Line::new(Point::new(left_x, y), Point::new(right_x, y))
+                .into_styled(guide_style)
+                .draw(target)?;
+        }
+
+        Ok(next_position)
+    }
+}
+
 impl<S: TextRenderer> Drawable for StaticText<'_, S> {
     type Color = S::Color;
     type Output = Point;
@@ -134,8 +647,8 @@ impl<S: TextRenderer> Drawable for StaticText<'_, S> {
         let left_x = self.rectangle.top_left.x;
         let right_x = left_x + size.width as i32;
 
-        for (line, position) in self.lines() {
-            if position.x > left_x {
+        for (head, tail, position) in self.lines() {
+            if self.fill_line_background && position.x > left_x {
                 self.character_style.draw_whitespace(
                     (position.x - left_x) as u32,
                     Point::new(left_x, position.y),
@@ -146,9 +659,20 @@ impl<S: TextRenderer> Drawable for StaticText<'_, S> {
 
             next_position =
                 self.character_style
-                    .draw_string(line, position, self.baseline, target)?;
+                    .draw_string(head, position, self.baseline, target)?;
+
+            if let Some((ellipsis, tail)) = tail {
+                for part in [ellipsis, tail] {
+                    next_position = self.character_style.draw_string(
+                        part,
+                        next_position,
+                        self.baseline,
+                        target,
+                    )?;
+                }
+            }
 
-            if next_position.x < right_x {
+            if self.fill_line_background && next_position.x < right_x {
                 self.character_style.draw_whitespace(
                     (right_x - next_position.x) as u32,
                     next_position,
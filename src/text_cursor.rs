@@ -0,0 +1,149 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    text::Baseline,
+    transform::Transform,
+    Drawable,
+};
+
+use crate::MultiMonoTextStyle;
+
+/// Text cursor drawable.
+///
+/// A text cursor draws a vertical bar in the text color at the caret position before the
+/// character with the given index. The bar spans the line height of the character style. Set
+/// [`visible`] to `false` to hide the cursor, e.g. to make it blink.
+///
+/// ```
+/// use embedded_graphics::{
+///     mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, primitives::Rectangle,
+///     text::Baseline,
+/// };
+/// use multi_mono_font::{ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, TextCursor};
+///
+/// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+/// let caret = style.char_offsets("abcd").nth(1).unwrap();
+///
+/// let mut display = MockDisplay::new();
+/// TextCursor::with_baseline("abcd", Point::zero(), 2, style, Baseline::Top)
+///     .draw(&mut display)
+///     .unwrap();
+///
+/// assert_eq!(
+///     display.affected_area(),
+///     Rectangle::new(Point::new(caret as i32, 0), Size::new(1, 9))
+/// );
+/// ```
+///
+/// [`visible`]: TextCursor::visible
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TextCursor<'a, C> {
+    /// The string.
+    pub text: &'a str,
+
+    /// The position of the start of the string.
+    pub position: Point,
+
+    /// The index of the character in front of which the cursor is drawn.
+    pub index: usize,
+
+    /// The character style.
+    pub character_style: MultiMonoTextStyle<'a, C>,
+
+    /// Text baseline.
+    pub baseline: Baseline,
+
+    /// Whether the cursor is drawn.
+    pub visible: bool,
+}
+
+impl<'a, C> TextCursor<'a, C> {
+    /// Creates a text cursor drawable with the default baseline.
+    pub const fn new(
+        text: &'a str,
+        position: Point,
+        index: usize,
+        character_style: MultiMonoTextStyle<'a, C>,
+    ) -> Self {
+        Self {
+            text,
+            position,
+            index,
+            character_style,
+            baseline: Baseline::Alphabetic,
+            visible: true,
+        }
+    }
+
+    /// Creates a text cursor drawable with the given baseline.
+    pub const fn with_baseline(
+        text: &'a str,
+        position: Point,
+        index: usize,
+        character_style: MultiMonoTextStyle<'a, C>,
+        baseline: Baseline,
+    ) -> Self {
+        Self {
+            text,
+            position,
+            index,
+            character_style,
+            baseline,
+            visible: true,
+        }
+    }
+}
+
+impl<C: PixelColor> TextCursor<'_, C> {
+    /// Returns the area of the cursor bar.
+    pub fn bounding_box(&self) -> Rectangle {
+        let caret = match self.index {
+            0 => 0,
+            index => self
+                .character_style
+                .char_offsets(self.text)
+                .take(index)
+                .last()
+                .unwrap_or_default(),
+        };
+        let (offset_y, height) = self.character_style.line_band(self.baseline);
+
+        Rectangle::new(
+            self.position + Point::new(caret as i32, -offset_y),
+            Size::new(1, height),
+        )
+    }
+}
+
+impl<C: Clone> Transform for TextCursor<'_, C> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..self.clone()
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+impl<C: PixelColor> Drawable for TextCursor<'_, C> {
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        if self.visible {
+            target.fill_solid(&self.bounding_box(), self.character_style.text_color)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Baseline},
+    transform::Transform,
+    Drawable,
+};
+
+/// Text orientation.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum TextOrientation {
+    /// Lines are drawn from left to right and stacked from top to bottom.
+    Horizontal,
+
+    /// Columns are drawn from top to bottom and stacked from right to left.
+    VerticalRL,
+
+    /// Columns are drawn from top to bottom and stacked from left to right.
+    VerticalLR,
+}
+
+/// Vertical text drawable.
+///
+/// In the vertical orientations each line of the text is drawn as a column of upright glyphs,
+/// which is common for CJK text. A `\n` starts a new column. All columns have the width of the
+/// widest glyph and narrower glyphs are centered in the column.
+///
+/// The tall box of vertical text is returned by [`bounding_box`]. [`TextRenderer::measure_string`]
+/// of the character style always measures a horizontal line and isn't affected by the
+/// orientation. Each glyph of a column is drawn and measured as a separate string, so options of
+/// the character style which apply to the start of a string, like the first glyph indent or the
+/// grid snapping of a [`MultiMonoTextStyle`], are applied to every glyph.
+///
+/// ```
+/// use embedded_graphics::{
+///     image::ImageRaw,
+///     mock_display::MockDisplay,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Rectangle,
+///     text::{renderer::TextRenderer, Baseline, Text},
+/// };
+/// use multi_mono_font::{
+///     mapping::StrGlyphMapping, CharSize, MultiMonoFont, MultiMonoLineHeight, MultiMonoTextStyle,
+///     TextOrientation, VerticalText,
+/// };
+///
+/// const CJK: MultiMonoFont = MultiMonoFont::new(
+///     ImageRaw::new(include_bytes!("../examples/fonts/HZ.bin"), 96),
+///     &StrGlyphMapping::new("字体测试", 0),
+///     CharSize::new(24, 24),
+///     0,
+///     24,
+/// );
+///
+/// let style = MultiMonoTextStyle::new(&[&CJK], MultiMonoLineHeight::Max, BinaryColor::On);
+/// let text = VerticalText::new("字体", Point::zero(), style, TextOrientation::VerticalRL);
+/// assert_eq!(
+///     text.bounding_box(),
+///     Rectangle::new(Point::zero(), Size::new(24, 48))
+/// );
+///
+/// // the character style measures a horizontal line
+/// let metrics = style.measure_string("字体", Point::zero(), Baseline::Top);
+/// assert_eq!(metrics.bounding_box.size, Size::new(48, 24));
+///
+/// let mut display = MockDisplay::new();
+/// text.draw(&mut display).unwrap();
+///
+/// // the second glyph is drawn below the first glyph
+/// let mut expected = MockDisplay::new();
+/// Text::with_baseline("字", Point::zero(), style, Baseline::Top)
+///     .draw(&mut expected)
+///     .unwrap();
+/// Text::with_baseline("体", Point::new(0, 24), style, Baseline::Top)
+///     .draw(&mut expected)
+///     .unwrap();
+///
+/// display.assert_eq(&expected);
+/// ```
+///
+/// Columns of narrower glyphs:
+///
+/// ```
+/// use embedded_graphics::{
+///     mock_display::MockDisplay,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Rectangle,
+///     text::{Baseline, Text},
+/// };
+/// use multi_mono_font::{
+///     ascii::FONT_6X9, MultiMonoLineHeight, MultiMonoTextStyle, TextOrientation, VerticalText,
+/// };
+///
+/// let style = MultiMonoTextStyle::new(&[&FONT_6X9], MultiMonoLineHeight::Max, BinaryColor::On);
+/// let text = VerticalText::new("AB", Point::zero(), style, TextOrientation::VerticalRL);
+/// assert_eq!(
+///     text.bounding_box(),
+///     Rectangle::new(Point::zero(), Size::new(6, 18))
+/// );
+///
+/// let mut display = MockDisplay::new();
+/// text.draw(&mut display).unwrap();
+///
+/// // the second glyph is drawn below the first glyph
+/// let mut expected = MockDisplay::new();
+/// Text::with_baseline("A", Point::zero(), style, Baseline::Top)
+///     .draw(&mut expected)
+///     .unwrap();
+/// Text::with_baseline("B", Point::new(0, 9), style, Baseline::Top)
+///     .draw(&mut expected)
+///     .unwrap();
+///
+/// display.assert_eq(&expected);
+/// ```
+///
+/// [`bounding_box`]: Dimensions::bounding_box
+/// [`MultiMonoTextStyle`]: crate::MultiMonoTextStyle
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct VerticalText<'a, S> {
+    /// The string.
+    pub text: &'a str,
+
+    /// The position of the top left corner of the text.
+    pub position: Point,
+
+    /// The character style.
+    pub character_style: S,
+
+    /// Text orientation.
+    pub orientation: TextOrientation,
+}
+
+impl<'a, S> VerticalText<'a, S> {
+    /// Creates a vertical text drawable.
+    pub const fn new(
+        text: &'a str,
+        position: Point,
+        character_style: S,
+        orientation: TextOrientation,
+    ) -> Self {
+        Self {
+            text,
+            position,
+            character_style,
+            orientation,
+        }
+    }
+}
+
+impl<S: TextRenderer> VerticalText<'_, S> {
+    fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .split('\n')
+            // remove trailing '\r' for '\r\n' line endings
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+    }
+
+    /// Returns the glyphs of a line together with their size.
+    fn glyphs<'t>(&'t self, line: &'t str) -> impl Iterator<Item = (&'t str, Size)> + 't {
+        line.char_indices().map(move |(index, c)| {
+            let glyph = &line[index..index + c.len_utf8()];
+            let size = self
+                .character_style
+                .measure_string(glyph, Point::zero(), Baseline::Top)
+                .bounding_box
+                .size;
+
+            (glyph, size)
+        })
+    }
+
+    fn column_width(&self) -> u32 {
+        self.lines()
+            .flat_map(|line| self.glyphs(line))
+            .map(|(_, size)| size.width)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn line_width(&self, line: &str) -> u32 {
+        self.character_style
+            .measure_string(line, Point::zero(), Baseline::Top)
+            .bounding_box
+            .size
+            .width
+    }
+}
+
+impl<S: TextRenderer> Dimensions for VerticalText<'_, S> {
+    fn bounding_box(&self) -> Rectangle {
+        let line_count = self.lines().count() as u32;
+
+        let size = match self.orientation {
+            TextOrientation::Horizontal => Size::new(
+                self.lines()
+                    .map(|line| self.line_width(line))
+                    .max()
+                    .unwrap_or_default(),
+                line_count * self.character_style.line_height(),
+            ),
+            TextOrientation::VerticalRL | TextOrientation::VerticalLR => Size::new(
+                line_count * self.column_width(),
+                self.lines()
+                    .map(|line| self.glyphs(line).map(|(_, size)| size.height).sum())
+                    .max()
+                    .unwrap_or_default(),
+            ),
+        };
+
+        Rectangle::new(self.position, size)
+    }
+}
+
+impl<S: Clone> Transform for VerticalText<'_, S> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..self.clone()
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+impl<S: TextRenderer> Drawable for VerticalText<'_, S> {
+    type Color = S::Color;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let mut next_position = self.position;
+
+        if self.orientation == TextOrientation::Horizontal {
+            let mut position = self.position;
+            for line in self.lines() {
+                next_position =
+                    self.character_style
+                        .draw_string(line, position, Baseline::Top, target)?;
+                position.y += self.character_style.line_height() as i32;
+            }
+
+            return Ok(next_position);
+        }
+
+        let column_width = self.column_width() as i32;
+        let column_count = self.lines().count() as i32;
+
+        for (column, line) in self.lines().enumerate() {
+            let column = match self.orientation {
+                TextOrientation::VerticalRL => column_count - 1 - column as i32,
+                _ => column as i32,
+            };
+            next_position = self.position + Point::new(column * column_width, 0);
+
+            for (glyph, size) in self.glyphs(line) {
+                let offset = (column_width - size.width as i32) / 2;
+                self.character_style.draw_string(
+                    glyph,
+                    next_position + Point::new(offset, 0),
+                    Baseline::Top,
+                    target,
+                )?;
+                next_position.y += size.height as i32;
+            }
+        }
+
+        Ok(next_position)
+    }
+}
@@ -0,0 +1,165 @@
+use embedded_graphics::{
+    geometry::Point,
+    text::{renderer::TextRenderer, Baseline},
+};
+
+/// Zero width space, which is a line break opportunity that isn't drawn.
+pub(crate) const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Returns `true` if lines can be broken at the character.
+///
+/// No-break spaces (U+00A0) aren't break opportunities.
+fn is_break(c: char) -> bool {
+    c == ' ' || c == ZERO_WIDTH_SPACE
+}
+
+/// Iterator over the lines of a text wrapped to a maximum width.
+///
+/// Lines are broken at explicit line breaks, at spaces and at zero width spaces. Words which are
+/// wider than the maximum width are split between characters. Spaces and zero width spaces at
+/// the wrap position are removed.
+///
+/// The available width of each line is reduced by the indent of the line.
+///
+/// Additional break opportunities can be provided as byte offsets into the text, which is useful
+/// for languages without spaces between words.
+pub(crate) struct WrapIter<'a, 's, S> {
+    source: &'a str,
+    text: &'a str,
+    breaks: &'a [usize],
+    character_style: &'s S,
+    width: u32,
+    first_line_indent: u32,
+    hanging_indent: u32,
+    first_line: bool,
+    finished: bool,
+}
+
+impl<'a, 's, S: TextRenderer> WrapIter<'a, 's, S> {
+    pub(crate) fn new(text: &'a str, character_style: &'s S, width: u32) -> Self {
+        Self {
+            source: text,
+            text,
+            breaks: &[],
+            character_style,
+            width,
+            first_line_indent: 0,
+            hanging_indent: 0,
+            first_line: true,
+            finished: false,
+        }
+    }
+
+    /// Sets the indent of the first line and of all following lines.
+    pub(crate) fn with_indents(mut self, first_line_indent: u32, hanging_indent: u32) -> Self {
+        self.first_line_indent = first_line_indent;
+        self.hanging_indent = hanging_indent;
+        self
+    }
+
+    /// Sets additional break opportunities.
+    ///
+    /// Each break is the byte offset of the first character after the break.
+    pub(crate) fn with_breaks(mut self, breaks: &'a [usize]) -> Self {
+        self.breaks = breaks;
+        self
+    }
+
+    /// Returns `true` if the line fits into the maximum width.
+    fn fits(&self, line: &str) -> bool {
+        let indent = if self.first_line {
+            self.first_line_indent
+        } else {
+            self.hanging_indent
+        };
+
+        self.character_style
+            .measure_string(line, Point::zero(), Baseline::Top)
+            .bounding_box
+            .size
+            .width
+            <= self.width.saturating_sub(indent)
+    }
+}
+
+impl<'a, S: TextRenderer> WrapIter<'a, '_, S> {
+    /// Returns the next line and `true` if the line was broken by wrapping instead of by an
+    /// explicit line break or the end of the text.
+    pub(crate) fn next_line(&mut self) -> Option<(&'a str, bool)> {
+        if self.finished {
+            return None;
+        }
+
+        let (line, rest) = match self.text.find('\n') {
+            Some(index) => (&self.text[..index], Some(&self.text[index + 1..])),
+            None => (self.text, None),
+        };
+        // remove trailing '\r' for '\r\n' line endings
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        if self.fits(line) {
+            match rest {
+                Some(rest) => self.text = rest,
+                None => self.finished = true,
+            }
+            self.first_line = false;
+
+            return Some((line, false));
+        }
+
+        let line_offset = self.text.as_ptr() as usize - self.source.as_ptr() as usize;
+        let mut fit_end = 0;
+        // end of the line at the last break opportunity and `true` if the break is a space
+        let mut wrap_end = None;
+        for (index, c) in line.char_indices() {
+            if index > 0 && self.breaks.contains(&(line_offset + index)) {
+                wrap_end = Some((index, false));
+            }
+
+            // a space which doesn't fit into the line still ends the line
+            if is_break(c) {
+                wrap_end = Some((index, true));
+            }
+
+            if !self.fits(&line[..index + c.len_utf8()]) {
+                break;
+            }
+            fit_end = index + c.len_utf8();
+        }
+
+        let (end, next_start) = match wrap_end {
+            Some((end, false)) => (end, end),
+            Some((end, true)) => (line[..end].trim_end_matches(is_break).len(), end),
+            // always emit at least one character to guarantee progress
+            None if fit_end == 0 => {
+                let end = line.chars().next().map_or(0, char::len_utf8);
+
+                (end, end)
+            }
+            None => (fit_end, fit_end),
+        };
+
+        // continuation lines don't start with the break characters at the wrap position
+        let next_start = line[next_start..]
+            .find(|c| !is_break(c))
+            .map_or(line.len(), |offset| next_start + offset);
+
+        match rest {
+            // the rest of the line was only break characters
+            Some(rest) if next_start == line.len() => self.text = rest,
+            None if next_start == line.len() => self.finished = true,
+            _ => self.text = &self.text[next_start..],
+        }
+        self.first_line = false;
+
+        Some((&line[..end], true))
+    }
+}
+
+impl<'a, S: TextRenderer> Iterator for WrapIter<'a, '_, S> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.next_line().map(|(line, _)| line)
+    }
+}